@@ -681,6 +681,33 @@ typedef struct CSliceRef_PhysicalMemoryMapping {
     uintptr_t len;
 } CSliceRef_PhysicalMemoryMapping;
 
+/**
+ * Wrapper around const slices.
+ *
+ * This is meant as a safe type to pass across the FFI boundary with similar semantics as regular
+ * slice. However, not all functionality is present, use the slice conversion functions.
+ *
+ * # Examples
+ *
+ * Simple conversion:
+ *
+ * ```
+ * use cglue::slice::CSliceRef;
+ *
+ * let arr = [0, 5, 3, 2];
+ *
+ * let cslice = CSliceRef::from(&arr[..]);
+ *
+ * let slice = cslice.as_slice();
+ *
+ * assert_eq!(&arr, slice);
+ * ```
+ */
+typedef struct CSliceRef_PhysicalReadData {
+    const PhysicalReadData *data;
+    uintptr_t len;
+} CSliceRef_PhysicalReadData;
+
 /**
  * FFI-safe 3 element tuple.
  */
@@ -1464,6 +1491,17 @@ typedef struct CTup2_Address__umem {
 
 typedef struct CTup2_Address__umem VtopRange;
 
+typedef struct CTup2_Address__umem PhysicalMemoryRange;
+
+typedef struct Callback_c_void__PhysicalMemoryRange {
+    void *context;
+    bool (*func)(void*, PhysicalMemoryRange);
+} Callback_c_void__PhysicalMemoryRange;
+
+typedef struct Callback_c_void__PhysicalMemoryRange OpaqueCallback_PhysicalMemoryRange;
+
+typedef OpaqueCallback_PhysicalMemoryRange PhysicalMemoryRangeCallback;
+
 /**
  * Wrapper around const slices.
  *
@@ -1804,6 +1842,8 @@ typedef struct PhysicalMemoryVtbl_OsInstanceContainer_CBox_c_void_____CArc_c_voi
     int32_t (*phys_write_raw_iter)(struct OsInstanceContainer_CBox_c_void_____CArc_c_void *cont, PhysicalWriteMemOps data);
     struct PhysicalMemoryMetadata (*metadata)(const struct OsInstanceContainer_CBox_c_void_____CArc_c_void *cont);
     void (*set_mem_map)(struct OsInstanceContainer_CBox_c_void_____CArc_c_void *cont, struct CSliceRef_PhysicalMemoryMapping _mem_map);
+    void (*prefetch)(struct OsInstanceContainer_CBox_c_void_____CArc_c_void *cont, struct CSliceRef_PhysicalReadData _ranges);
+    void (*phys_mem_ranges)(const struct OsInstanceContainer_CBox_c_void_____CArc_c_void *cont, PhysicalMemoryRangeCallback callback);
     MemoryViewBase_CBox_c_void_____CArc_c_void (*into_phys_view)(struct OsInstanceContainer_CBox_c_void_____CArc_c_void cont);
     MemoryViewBase_CBox_c_void_____CArc_c_void (*phys_view)(struct OsInstanceContainer_CBox_c_void_____CArc_c_void *cont);
 } PhysicalMemoryVtbl_OsInstanceContainer_CBox_c_void_____CArc_c_void;
@@ -2131,6 +2171,8 @@ typedef struct PhysicalMemoryVtbl_ConnectorInstanceContainer_CBox_c_void_____CAr
     int32_t (*phys_write_raw_iter)(struct ConnectorInstanceContainer_CBox_c_void_____CArc_c_void *cont, PhysicalWriteMemOps data);
     struct PhysicalMemoryMetadata (*metadata)(const struct ConnectorInstanceContainer_CBox_c_void_____CArc_c_void *cont);
     void (*set_mem_map)(struct ConnectorInstanceContainer_CBox_c_void_____CArc_c_void *cont, struct CSliceRef_PhysicalMemoryMapping _mem_map);
+    void (*prefetch)(struct ConnectorInstanceContainer_CBox_c_void_____CArc_c_void *cont, struct CSliceRef_PhysicalReadData _ranges);
+    void (*phys_mem_ranges)(const struct ConnectorInstanceContainer_CBox_c_void_____CArc_c_void *cont, PhysicalMemoryRangeCallback callback);
     MemoryViewBase_CBox_c_void_____CArc_c_void (*into_phys_view)(struct ConnectorInstanceContainer_CBox_c_void_____CArc_c_void cont);
     MemoryViewBase_CBox_c_void_____CArc_c_void (*phys_view)(struct ConnectorInstanceContainer_CBox_c_void_____CArc_c_void *cont);
 } PhysicalMemoryVtbl_ConnectorInstanceContainer_CBox_c_void_____CArc_c_void;
@@ -2784,6 +2826,16 @@ static inline void mf_osinstance_set_mem_map(void *self, struct CSliceRef_Physic
 
 }
 
+static inline void mf_osinstance_prefetch(void *self, struct CSliceRef_PhysicalReadData _ranges)  {
+(((struct OsInstance_CBox_c_void_____CArc_c_void *)self)->vtbl_physicalmemory)->prefetch(&((struct OsInstance_CBox_c_void_____CArc_c_void *)self)->container, _ranges);
+
+}
+
+static inline void mf_osinstance_phys_mem_ranges(const void *self, PhysicalMemoryRangeCallback callback)  {
+(((const struct OsInstance_CBox_c_void_____CArc_c_void *)self)->vtbl_physicalmemory)->phys_mem_ranges(&((const struct OsInstance_CBox_c_void_____CArc_c_void *)self)->container, callback);
+
+}
+
 static inline MemoryViewBase_CBox_c_void_____CArc_c_void mf_osinstance_into_phys_view(struct OsInstance_CBox_c_void_____CArc_c_void self)  {
     CArc_c_void ___ctx = ctx_arc_clone(&self.container.context);
     MemoryViewBase_CBox_c_void_____CArc_c_void __ret = (self.vtbl_physicalmemory)->into_phys_view(self.container);
@@ -3229,6 +3281,16 @@ static inline void mf_connectorinstance_set_mem_map(void *self, struct CSliceRef
 
 }
 
+static inline void mf_connectorinstance_prefetch(void *self, struct CSliceRef_PhysicalReadData _ranges)  {
+(((struct ConnectorInstance_CBox_c_void_____CArc_c_void *)self)->vtbl_physicalmemory)->prefetch(&((struct ConnectorInstance_CBox_c_void_____CArc_c_void *)self)->container, _ranges);
+
+}
+
+static inline void mf_connectorinstance_phys_mem_ranges(const void *self, PhysicalMemoryRangeCallback callback)  {
+(((const struct ConnectorInstance_CBox_c_void_____CArc_c_void *)self)->vtbl_physicalmemory)->phys_mem_ranges(&((const struct ConnectorInstance_CBox_c_void_____CArc_c_void *)self)->container, callback);
+
+}
+
 static inline MemoryViewBase_CBox_c_void_____CArc_c_void mf_connectorinstance_into_phys_view(struct ConnectorInstance_CBox_c_void_____CArc_c_void self)  {
     CArc_c_void ___ctx = ctx_arc_clone(&self.container.context);
     MemoryViewBase_CBox_c_void_____CArc_c_void __ret = (self.vtbl_physicalmemory)->into_phys_view(self.container);
@@ -1003,6 +1003,10 @@ struct CGlueTraitObj {
 template<typename CGlueInst = CBox<void>, typename CGlueCtx = CArc<void>>
 using MemoryViewBase = CGlueTraitObj<CGlueInst, MemoryViewVtbl<CGlueObjContainer<CGlueInst, CGlueCtx, MemoryViewRetTmp<CGlueCtx>>>, CGlueCtx, MemoryViewRetTmp<CGlueCtx>>;
 
+using PhysicalMemoryRange = CTup2<Address, umem>;
+
+using PhysicalMemoryRangeCallback = OpaqueCallback<PhysicalMemoryRange>;
+
 /**
  * CGlue vtable for trait PhysicalMemory.
  *
@@ -1015,6 +1019,8 @@ struct PhysicalMemoryVtbl {
     int32_t (*phys_write_raw_iter)(CGlueC *cont, PhysicalWriteMemOps data);
     PhysicalMemoryMetadata (*metadata)(const CGlueC *cont);
     void (*set_mem_map)(CGlueC *cont, CSliceRef<PhysicalMemoryMapping> _mem_map);
+    void (*prefetch)(CGlueC *cont, CSliceRef<PhysicalReadData> _ranges);
+    void (*phys_mem_ranges)(const CGlueC *cont, PhysicalMemoryRangeCallback callback);
     MemoryViewBase<CBox<void>, Context> (*into_phys_view)(CGlueC cont);
     MemoryViewBase<CBox<void>, Context> (*phys_view)(CGlueC *cont);
 };
@@ -1027,6 +1033,8 @@ constexpr PhysicalMemoryVtblImpl() :
         &Impl::phys_write_raw_iter,
         &Impl::metadata,
         &Impl::set_mem_map,
+        &Impl::prefetch,
+        &Impl::phys_mem_ranges,
         &Impl::into_phys_view,
         &Impl::phys_view
     } {}
@@ -1231,6 +1239,16 @@ struct ConnectorInstance {
 
     }
 
+    inline void prefetch(CSliceRef<PhysicalReadData> _ranges) noexcept {
+    (this->vtbl_physicalmemory)->prefetch(&this->container, _ranges);
+
+    }
+
+    inline void phys_mem_ranges(PhysicalMemoryRangeCallback callback) const noexcept {
+    (this->vtbl_physicalmemory)->phys_mem_ranges(&this->container, callback);
+
+    }
+
     inline MemoryViewBase<CBox<void>, Context> into_phys_view() && noexcept {
         auto ___ctx = StoreAll()[this->container.clone_context(), StoreAll()];
         MemoryViewBase<CBox<void>, Context> __ret = (this->vtbl_physicalmemory)->into_phys_view(this->container);
@@ -2764,6 +2782,16 @@ struct OsInstance {
 
     }
 
+    inline void prefetch(CSliceRef<PhysicalReadData> _ranges) noexcept {
+    (this->vtbl_physicalmemory)->prefetch(&this->container, _ranges);
+
+    }
+
+    inline void phys_mem_ranges(PhysicalMemoryRangeCallback callback) const noexcept {
+    (this->vtbl_physicalmemory)->phys_mem_ranges(&this->container, callback);
+
+    }
+
     inline MemoryViewBase<CBox<void>, Context> into_phys_view() && noexcept {
         auto ___ctx = StoreAll()[this->container.clone_context(), StoreAll()];
         MemoryViewBase<CBox<void>, Context> __ret = (this->vtbl_physicalmemory)->into_phys_view(this->container);
@@ -3238,6 +3266,16 @@ struct CGlueTraitObj<T, PhysicalMemoryVtbl<CGlueObjContainer<T, C, R>>, C, R> {
 
     }
 
+    inline void prefetch(CSliceRef<PhysicalReadData> _ranges) noexcept {
+    (this->vtbl)->prefetch(&this->container, _ranges);
+
+    }
+
+    inline void phys_mem_ranges(PhysicalMemoryRangeCallback callback) const noexcept {
+    (this->vtbl)->phys_mem_ranges(&this->container, callback);
+
+    }
+
     inline MemoryViewBase<CBox<void>, Context> into_phys_view() && noexcept {
         auto ___ctx = StoreAll()[this->container.clone_context(), StoreAll()];
         MemoryViewBase<CBox<void>, Context> __ret = (this->vtbl)->into_phys_view(this->container);
@@ -26,7 +26,7 @@ use crate::types::size;
 /// See the [wikipedia article](https://en.wikipedia.org/wiki/Endianness) for more information on the subject.
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
-#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 #[cfg_attr(feature = "abi_stable", derive(::abi_stable::StableAbi))]
 pub enum Endianess {
     /// Little Endianess
@@ -117,6 +117,43 @@ pub trait Architecture: Send + Sync + 'static {
     fn ident(&self) -> ArchitectureIdent;
 }
 
+impl dyn Architecture {
+    /// Returns `true` if both architectures belong to the same family.
+    ///
+    /// See [`ArchitectureIdent::same_family`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::architecture::x86::{x32, x32_pae, x64};
+    ///
+    /// assert!(x32::ARCH.same_family(x32_pae::ARCH));
+    /// assert!(!x32::ARCH.same_family(x64::ARCH));
+    /// ```
+    pub fn same_family(&self, other: ArchitectureObj) -> bool {
+        self.ident().same_family(&other.ident())
+    }
+
+    /// Returns the size of a pointer on this architecture in bytes.
+    ///
+    /// This is equivalent to [`Architecture::size_addr`] and is the amount of bytes
+    /// read by functions like `read_addr_arch`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::architecture::x86::{x32, x32_pae, x64};
+    ///
+    /// assert_eq!(x32::ARCH.pointer_size(), 4);
+    /// assert_eq!(x32_pae::ARCH.pointer_size(), 4);
+    /// assert_eq!(x64::ARCH.pointer_size(), 8);
+    /// ```
+    #[inline]
+    pub fn pointer_size(&self) -> usize {
+        self.size_addr()
+    }
+}
+
 impl std::fmt::Debug for ArchitectureObj {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ArchitectureObj")
@@ -178,6 +215,28 @@ impl ArchitectureIdent {
     pub fn into_obj(self) -> ArchitectureObj {
         self.into()
     }
+
+    /// Returns `true` if both identifiers belong to the same architecture family.
+    ///
+    /// Unlike a strict comparison this ignores address extensions, e.g. x86 and x86 PAE
+    /// are treated as the same 32-bit family. The bitness is still taken into account.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::architecture::ArchitectureIdent;
+    ///
+    /// assert!(ArchitectureIdent::X86(32, false).same_family(&ArchitectureIdent::X86(32, true)));
+    /// assert!(!ArchitectureIdent::X86(32, false).same_family(&ArchitectureIdent::X86(64, false)));
+    /// ```
+    pub fn same_family(&self, other: &ArchitectureIdent) -> bool {
+        match (self, other) {
+            (ArchitectureIdent::X86(a, _), ArchitectureIdent::X86(b, _)) => a == b,
+            (ArchitectureIdent::AArch64(_), ArchitectureIdent::AArch64(_)) => true,
+            (ArchitectureIdent::Unknown(a), ArchitectureIdent::Unknown(b)) => a == b,
+            _ => false,
+        }
+    }
 }
 
 impl From<ArchitectureIdent> for ArchitectureObj {
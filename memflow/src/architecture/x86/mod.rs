@@ -62,6 +62,16 @@ impl X86VirtualTranslate {
     pub fn new(arch: &'static X86Architecture, dtb: Address) -> Self {
         Self { arch, dtb }
     }
+
+    /// Returns the directory table base used by this translator.
+    pub fn dtb(&self) -> Address {
+        self.dtb
+    }
+
+    /// Returns a translator of the same architecture with a different directory table base.
+    pub fn with_dtb(&self, dtb: Address) -> Self {
+        Self::new(self.arch, dtb)
+    }
 }
 
 impl VirtualTranslate3 for X86VirtualTranslate {
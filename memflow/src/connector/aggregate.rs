@@ -0,0 +1,331 @@
+///! Basic connector which combines multiple memory backings into a single physical address space.
+use crate::error::Result;
+use crate::iter::SplitAtIndex;
+use crate::mem::{
+    opt_call, MemOps, PhysicalMemory, PhysicalMemoryMapping, PhysicalMemoryMetadata,
+    PhysicalMemoryRangeCallback, PhysicalReadMemOps, PhysicalWriteMemOps,
+};
+use crate::types::{umem, Address, PhysicalAddress};
+
+use crate::cglue::*;
+
+use std::prelude::v1::*;
+
+/// Physical memory object that dispatches operations to multiple backings.
+///
+/// Every backing covers a range of the physical address space. Operations are split up
+/// and forwarded to the backing owning the respective range, addresses are not translated.
+/// Parts of an operation that are not covered by any backing are reported as failures.
+///
+/// This allows overlaying multiple connectors, for example a live connector with a memory
+/// dump that fills in the ranges the live connector is unable to access.
+///
+/// In order to combine connectors of different types the backings can be plugin
+/// instances (e.g. [`ConnectorInstanceArcBox`](crate::plugins::ConnectorInstanceArcBox)).
+///
+/// # Examples
+///
+/// ```
+/// use memflow::types::{size, umem, Address};
+/// use memflow::mem::PhysicalMemory;
+/// use memflow::connector::AggregateMemory;
+/// use memflow::dummy::DummyMemory;
+///
+/// let mut mem = AggregateMemory::new();
+/// mem.push(Address::null(), size::mb(1) as umem, DummyMemory::new(size::mb(1)))
+///     .push(
+///         Address::from(size::mb(2)),
+///         size::mb(1) as umem,
+///         DummyMemory::new(size::mb(4)),
+///     );
+///
+/// let ranges = mem.phys_mem_ranges_vec();
+/// assert_eq!(ranges.len(), 2);
+/// ```
+#[derive(Clone)]
+pub struct AggregateMemory<T> {
+    // sorted by base address, ranges never overlap
+    backings: Vec<(Address, umem, T)>,
+}
+
+impl<T> Default for AggregateMemory<T> {
+    fn default() -> Self {
+        Self {
+            backings: Vec::new(),
+        }
+    }
+}
+
+impl<T: PhysicalMemory> AggregateMemory<T> {
+    /// Constructs a new aggregate without any backings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a backing that serves the range `base..base + size`.
+    ///
+    /// When adding overlapping ranges this function will panic!
+    pub fn push(&mut self, base: Address, size: umem, mem: T) -> &mut Self {
+        let end = base.to_umem().saturating_add(size);
+
+        let idx = self
+            .backings
+            .partition_point(|(b, _, _)| b.to_umem() < base.to_umem());
+
+        if let Some((prev_base, prev_size, _)) =
+            idx.checked_sub(1).and_then(|i| self.backings.get(i))
+        {
+            if prev_base.to_umem().saturating_add(*prev_size) > base.to_umem() {
+                panic!(
+                    "aggregate backing {:x}-{:x} overlaps with {:x}-{:x}",
+                    base,
+                    end,
+                    prev_base,
+                    prev_base.to_umem().saturating_add(*prev_size)
+                );
+            }
+        }
+
+        if let Some((next_base, next_size, _)) = self.backings.get(idx) {
+            if next_base.to_umem() < end {
+                panic!(
+                    "aggregate backing {:x}-{:x} overlaps with {:x}-{:x}",
+                    base,
+                    end,
+                    next_base,
+                    next_base.to_umem().saturating_add(*next_size)
+                );
+            }
+        }
+
+        self.backings.insert(idx, (base, size, mem));
+        self
+    }
+
+    /// Consumes self and returns all backings with their ranges.
+    pub fn into_inner(self) -> Vec<(Address, umem, T)> {
+        self.backings
+    }
+
+    /// Splits up operations by the backing that owns them.
+    ///
+    /// Parts that are not covered by any backing are passed to `out_fail`.
+    fn split_ops<D: SplitAtIndex>(
+        &self,
+        inp: impl Iterator<Item = CTup3<PhysicalAddress, Address, D>>,
+        mut out_fail: impl FnMut(CTup2<Address, D>),
+    ) -> Vec<Vec<CTup3<PhysicalAddress, Address, D>>> {
+        let mut ret = self.backings.iter().map(|_| vec![]).collect::<Vec<_>>();
+
+        for CTup3(addr, meta_addr, data) in inp {
+            let to_phys = |a: Address| {
+                if addr.has_page() {
+                    PhysicalAddress::with_page(a, addr.page_type(), addr.page_size())
+                } else {
+                    a.into()
+                }
+            };
+
+            let mut rest = Some(CTup3(addr.address(), meta_addr, data));
+
+            while let Some(op) = rest.take() {
+                let cur = op.0.to_umem();
+
+                // first backing that ends after the current address
+                let idx = self
+                    .backings
+                    .partition_point(|(base, size, _)| base.to_umem().saturating_add(*size) <= cur);
+
+                match self.backings.get(idx) {
+                    Some((base, size, _)) if base.to_umem() <= cur => {
+                        let (head, tail) = op.split_at(base.to_umem().saturating_add(*size) - cur);
+                        if let Some(CTup3(a, m, d)) = head {
+                            ret[idx].push(CTup3(to_phys(a), m, d));
+                        }
+                        rest = tail;
+                    }
+                    Some((base, _, _)) => {
+                        let (head, tail) = op.split_at(base.to_umem() - cur);
+                        if let Some(CTup3(_, m, d)) = head {
+                            out_fail(CTup2(m, d));
+                        }
+                        rest = tail;
+                    }
+                    None => {
+                        let CTup3(_, m, d) = op;
+                        out_fail(CTup2(m, d));
+                    }
+                }
+            }
+        }
+
+        ret
+    }
+}
+
+#[allow(clippy::needless_option_as_deref)]
+impl<T: PhysicalMemory> PhysicalMemory for AggregateMemory<T> {
+    fn phys_read_raw_iter(
+        &mut self,
+        MemOps {
+            inp,
+            mut out,
+            mut out_fail,
+        }: PhysicalReadMemOps,
+    ) -> Result<()> {
+        let ops = self.split_ops(inp, |data| {
+            opt_call(out_fail.as_deref_mut(), data);
+        });
+
+        for ((_, _, mem), ops) in self.backings.iter_mut().zip(ops) {
+            if !ops.is_empty() {
+                MemOps::with_raw(
+                    ops.into_iter(),
+                    out.as_deref_mut(),
+                    out_fail.as_deref_mut(),
+                    |data| mem.phys_read_raw_iter(data),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn phys_write_raw_iter(
+        &mut self,
+        MemOps {
+            inp,
+            mut out,
+            mut out_fail,
+        }: PhysicalWriteMemOps,
+    ) -> Result<()> {
+        let ops = self.split_ops(inp, |data| {
+            opt_call(out_fail.as_deref_mut(), data);
+        });
+
+        for ((_, _, mem), ops) in self.backings.iter_mut().zip(ops) {
+            if !ops.is_empty() {
+                MemOps::with_raw(
+                    ops.into_iter(),
+                    out.as_deref_mut(),
+                    out_fail.as_deref_mut(),
+                    |data| mem.phys_write_raw_iter(data),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn metadata(&self) -> PhysicalMemoryMetadata {
+        let max_address = self
+            .backings
+            .last()
+            .map(|(base, size, _)| base.to_umem().saturating_add(*size).saturating_sub(1))
+            .unwrap_or_default();
+
+        let real_size = self.backings.iter().fold(0, |s, (_, size, _)| s + size);
+
+        let readonly = !self.backings.is_empty()
+            && self
+                .backings
+                .iter()
+                .all(|(_, _, mem)| mem.metadata().readonly);
+
+        let ideal_batch_size = self
+            .backings
+            .iter()
+            .map(|(_, _, mem)| mem.metadata().ideal_batch_size)
+            .min()
+            .unwrap_or(u32::MAX);
+
+        PhysicalMemoryMetadata {
+            max_address: max_address.into(),
+            real_size,
+            readonly,
+            ideal_batch_size,
+        }
+    }
+
+    fn set_mem_map(&mut self, mem_map: &[PhysicalMemoryMapping]) {
+        self.backings
+            .iter_mut()
+            .for_each(|(_, _, mem)| mem.set_mem_map(mem_map));
+    }
+
+    fn phys_mem_ranges(&self, mut callback: PhysicalMemoryRangeCallback) {
+        for (base, size, mem) in self.backings.iter() {
+            let (start, end) = (base.to_umem(), base.to_umem().saturating_add(*size));
+
+            // only report the parts of the backed ranges that this backing serves
+            for CTup2(range_base, range_size) in mem.phys_mem_ranges_vec() {
+                let range_start = core::cmp::max(range_base.to_umem(), start);
+                let range_end =
+                    core::cmp::min(range_base.to_umem().saturating_add(range_size), end);
+
+                if range_start < range_end
+                    && !callback.call(CTup2(range_start.into(), range_end - range_start))
+                {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "plugins")]
+::cglue::cglue_impl_group!(
+    AggregateMemory<T: PhysicalMemory>,
+    crate::plugins::ConnectorInstance,
+    {}
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dummy::DummyMemory;
+    use crate::error::PartialError;
+    use crate::mem::MemoryView;
+    use crate::types::size;
+
+    #[test]
+    fn aggregate_dispatch() {
+        let mut low = DummyMemory::new(size::kb(8));
+        low.phys_write(0xffc.into(), &[1u8; 4]).unwrap();
+        let mut high = DummyMemory::new(size::kb(16));
+        high.phys_write(0x2000.into(), &[2u8; 4]).unwrap();
+
+        let mut mem = AggregateMemory::new();
+        mem.push(Address::null(), 0x1000, low)
+            .push(0x2000.into(), 0x1000, high);
+
+        assert_eq!(mem.metadata().max_address, Address::from(0x2fff));
+        assert_eq!(mem.metadata().real_size, 0x2000);
+
+        let mut buf = [0u8; 4];
+        mem.phys_view()
+            .read_raw_into(0xffc.into(), &mut buf)
+            .unwrap();
+        assert_eq!(buf, [1; 4]);
+
+        mem.phys_view()
+            .read_raw_into(0x2000.into(), &mut buf)
+            .unwrap();
+        assert_eq!(buf, [2; 4]);
+
+        // reads spanning the gap between the backings only partially succeed
+        let mut buf = [0u8; 0x1008];
+        let ret = mem.phys_view().read_raw_into(0xffc.into(), &mut buf);
+        assert!(matches!(ret, Err(PartialError::PartialVirtualRead(_))));
+        assert_eq!(buf[..4], [1; 4]);
+        assert_eq!(buf[0x1004..], [2; 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn aggregate_overlap() {
+        let mut mem = AggregateMemory::new();
+        mem.push(Address::null(), 0x2000, DummyMemory::new(size::kb(8)))
+            .push(0x1000.into(), 0x1000, DummyMemory::new(size::kb(8)));
+    }
+}
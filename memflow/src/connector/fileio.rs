@@ -2,8 +2,8 @@
 
 use crate::error::{Error, ErrorKind, ErrorOrigin, Result};
 use crate::mem::{
-    opt_call, MemoryMap, PhysicalMemory, PhysicalMemoryMetadata, PhysicalReadMemOps,
-    PhysicalWriteMemOps,
+    opt_call, MemoryMap, PhysicalMemory, PhysicalMemoryMetadata, PhysicalMemoryRangeCallback,
+    PhysicalReadMemOps, PhysicalWriteMemOps,
 };
 use crate::types::{umem, Address};
 
@@ -212,6 +212,14 @@ impl<T: Seek + Read + Write + Send> PhysicalMemory for FileIoMemory<T> {
             ideal_batch_size: u32::MAX,
         }
     }
+
+    fn phys_mem_ranges(&self, mut callback: PhysicalMemoryRangeCallback) {
+        self.mem_map
+            .iter()
+            .map(|map| CTup2(map.base(), map.output().1))
+            .take_while(|range| callback.call(*range))
+            .for_each(|_| {});
+    }
 }
 
 cglue_impl_group!(
@@ -219,3 +227,25 @@ cglue_impl_group!(
     crate::plugins::ConnectorInstance,
     {}
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn fileio_mem_ranges() {
+        let mut mem_map = MemoryMap::new();
+        mem_map.push_remap(0x1000.into(), 0x1000, 0x0.into());
+        mem_map.push_remap(0x4000.into(), 0x2000, 0x1000.into());
+
+        let mem = FileIoMemory::with_mem_map(Cursor::new(vec![0u8; 0x3000]), mem_map).unwrap();
+
+        let ranges = mem.phys_mem_ranges_vec();
+        assert_eq!(ranges.len(), 2);
+        assert_eq!(ranges[0].0, Address::from(0x1000));
+        assert_eq!(ranges[0].1, 0x1000);
+        assert_eq!(ranges[1].0, Address::from(0x4000));
+        assert_eq!(ranges[1].1, 0x2000);
+    }
+}
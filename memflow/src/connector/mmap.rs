@@ -1,8 +1,8 @@
 ///! Basic connector which works on mapped memory.
 use crate::error::{Error, ErrorKind, ErrorOrigin, Result};
 use crate::mem::{
-    opt_call, MemoryMap, PhysicalMemory, PhysicalMemoryMetadata, PhysicalReadMemOps,
-    PhysicalWriteMemOps,
+    opt_call, MemoryMap, PhysicalMemory, PhysicalMemoryMetadata, PhysicalMemoryRangeCallback,
+    PhysicalReadMemOps, PhysicalWriteMemOps,
 };
 use crate::types::{umem, Address};
 
@@ -133,6 +133,15 @@ impl<'a, F: AsRef<MemoryMap<&'a mut [u8]>> + Send> PhysicalMemory
             ideal_batch_size: u32::MAX,
         }
     }
+
+    fn phys_mem_ranges(&self, mut callback: PhysicalMemoryRangeCallback) {
+        self.info
+            .as_ref()
+            .iter()
+            .map(|map| CTup2(map.base(), map.output().len() as umem))
+            .take_while(|range| callback.call(*range))
+            .for_each(|_| {});
+    }
 }
 
 #[allow(clippy::needless_option_as_deref)]
@@ -175,6 +184,15 @@ impl<'a, F: AsRef<MemoryMap<&'a [u8]>> + Send> PhysicalMemory
             ideal_batch_size: u32::MAX,
         }
     }
+
+    fn phys_mem_ranges(&self, mut callback: PhysicalMemoryRangeCallback) {
+        self.info
+            .as_ref()
+            .iter()
+            .map(|map| CTup2(map.base(), map.output().len() as umem))
+            .take_while(|range| callback.call(*range))
+            .for_each(|_| {});
+    }
 }
 
 #[cfg(feature = "plugins")]
@@ -27,6 +27,10 @@ pub mod mmap;
 #[doc(hidden)]
 pub use mmap::MappedPhysicalMemory;
 
+pub mod aggregate;
+#[doc(hidden)]
+pub use aggregate::AggregateMemory;
+
 pub mod cpu_state;
 #[doc(hidden)]
 pub use cpu_state::{ConnectorCpuState, CpuState};
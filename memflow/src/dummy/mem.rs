@@ -69,6 +69,16 @@ impl PhysicalMemory for DummyMemory {
     fn set_mem_map(&mut self, mem_map: &[PhysicalMemoryMapping]) {
         self.mem.set_mem_map(mem_map)
     }
+
+    #[inline]
+    fn prefetch(&mut self, ranges: &[PhysicalReadData]) {
+        self.mem.prefetch(ranges)
+    }
+
+    #[inline]
+    fn phys_mem_ranges(&self, callback: PhysicalMemoryRangeCallback) {
+        self.mem.phys_mem_ranges(callback)
+    }
 }
 
 pub fn parse_size(args: &Args) -> Result<usize> {
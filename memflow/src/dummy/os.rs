@@ -272,6 +272,7 @@ impl DummyOs {
             dtb,
             map_size,
             modules: vec![],
+            exports: vec![],
         }
     }
 
@@ -589,6 +590,16 @@ impl PhysicalMemory for DummyOs {
     fn set_mem_map(&mut self, mem_map: &[PhysicalMemoryMapping]) {
         self.mem.set_mem_map(mem_map)
     }
+
+    #[inline]
+    fn prefetch(&mut self, ranges: &[PhysicalReadData]) {
+        self.mem.prefetch(ranges)
+    }
+
+    #[inline]
+    fn phys_mem_ranges(&self, callback: PhysicalMemoryRangeCallback) {
+        self.mem.phys_mem_ranges(callback)
+    }
 }
 
 #[doc(hidden)]
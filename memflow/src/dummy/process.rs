@@ -18,6 +18,8 @@ pub struct DummyProcessInfo {
     pub map_size: usize,
     pub dtb: Address,
     pub modules: Vec<ModuleInfo>,
+    /// Exports of modules, keyed by the module structure address
+    pub exports: Vec<(Address, ExportInfo)>,
 }
 
 impl DummyProcessInfo {
@@ -41,6 +43,19 @@ impl DummyProcessInfo {
         }
     }
 
+    /// Registers an export of the module with the given structure address
+    ///
+    /// Modules with registered exports report those instead of parsing their image.
+    pub fn add_export(&mut self, module: Address, name: &str, offset: umem) {
+        self.exports.push((
+            module,
+            ExportInfo {
+                name: name.into(),
+                offset,
+            },
+        ));
+    }
+
     pub fn translator(&self) -> impl VirtualTranslate3 {
         x64::new_translator(self.dtb)
     }
@@ -129,7 +144,17 @@ impl<T: MemoryView> Process for DummyProcess<T> {
         info: &ModuleInfo,
         callback: ExportCallback,
     ) -> Result<()> {
-        crate::os::util::module_export_list_callback(self, info, callback)
+        if self.proc.exports.iter().any(|(m, _)| *m == info.address) {
+            self.proc
+                .exports
+                .iter()
+                .filter(|(m, _)| *m == info.address)
+                .map(|(_, e)| e.clone())
+                .feed_into(callback);
+            Ok(())
+        } else {
+            crate::os::util::module_export_list_callback(self, info, callback)
+        }
     }
 
     fn module_section_list_callback(
@@ -179,7 +204,7 @@ impl<T: MemoryView> MemoryView for DummyProcess<T> {
 mod tests {
     use super::super::*;
     use crate::os::{Os, Process};
-    use crate::types::size;
+    use crate::types::{size, Address};
 
     #[test]
     pub fn primary_module() {
@@ -193,4 +218,136 @@ mod tests {
         let module = prc.primary_module();
         assert!(module.is_ok())
     }
+
+    #[test]
+    pub fn module_list_filtered_by_address_info() {
+        let mem = DummyMemory::new(size::mb(64));
+        let mut os = DummyOs::new(mem);
+
+        let pid = os.alloc_process(size::mb(60), &[]);
+        let mut prc = os.process_by_pid(pid).unwrap();
+        prc.proc.add_modules(10, size::kb(1));
+
+        assert_eq!(prc.module_address_list(None).unwrap().len(), 10);
+
+        let second = prc.module_list().unwrap()[1].address;
+        let filtered = prc
+            .module_list_filtered_by_address_info(None, |m| m.address == second)
+            .unwrap();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].address, second);
+
+        assert!(prc
+            .module_list_filtered_by_address_info(None, |_| false)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    pub fn module_list_limited() {
+        let mem = DummyMemory::new(size::mb(64));
+        let mut os = DummyOs::new(mem);
+
+        let pid = os.alloc_process(size::mb(60), &[]);
+        let mut prc = os.process_by_pid(pid).unwrap();
+        prc.proc.add_modules(10, size::kb(1));
+
+        assert_eq!(prc.module_list_limited(None, 3).unwrap().len(), 3);
+        assert_eq!(prc.module_list_limited(None, 20).unwrap().len(), 10);
+        assert!(prc.module_list_limited(None, 0).unwrap().is_empty());
+    }
+
+    #[test]
+    pub fn module_list_sorted() {
+        let mem = DummyMemory::new(size::mb(64));
+        let mut os = DummyOs::new(mem);
+
+        let pid = os.alloc_process(size::mb(60), &[]);
+        let mut prc = os.process_by_pid(pid).unwrap();
+        prc.proc.add_modules(4, size::kb(1));
+
+        // assign distinct bases in shuffled order
+        let base = prc.proc.info.address;
+        for (module, offset) in prc
+            .proc
+            .modules
+            .iter_mut()
+            .zip([0x3000usize, 0x1000, 0x4000, 0x2000].iter())
+        {
+            module.base = base + *offset;
+        }
+
+        let modules = prc.module_list_sorted().unwrap();
+        assert_eq!(
+            modules.iter().map(|m| m.base).collect::<Vec<_>>(),
+            vec![
+                base + 0x1000usize,
+                base + 0x2000usize,
+                base + 0x3000usize,
+                base + 0x4000usize
+            ]
+        );
+        // the module structures are reordered along with their bases
+        assert_eq!(
+            modules.iter().map(|m| m.address).collect::<Vec<_>>(),
+            vec![
+                Address::from(1024),
+                Address::from(3072),
+                Address::from(0),
+                Address::from(2048)
+            ]
+        );
+    }
+
+    #[test]
+    pub fn nearest_export_no_module() {
+        let mem = DummyMemory::new(size::mb(64));
+        let mut os = DummyOs::new(mem);
+
+        let pid = os.alloc_process(size::mb(60), &[]);
+        let mut prc = os.process_by_pid(pid).unwrap();
+        prc.proc.add_modules(10, size::kb(1));
+
+        assert!(prc.nearest_export(Address::invalid()).unwrap().is_none());
+    }
+
+    #[test]
+    pub fn nearest_export_in_module() {
+        let mem = DummyMemory::new(size::mb(64));
+        let mut os = DummyOs::new(mem);
+
+        let pid = os.alloc_process(size::mb(60), &[]);
+        let mut prc = os.process_by_pid(pid).unwrap();
+        prc.proc.add_modules(1, size::kb(64));
+        let module = prc.proc.modules[0].clone();
+
+        prc.proc.add_export(module.address, "first", 0x100);
+        prc.proc.add_export(module.address, "second", 0x800);
+        prc.proc.add_export(module.address, "third", 0x2000);
+
+        let (found, export, delta) = prc
+            .nearest_export(module.base + 0x900usize)
+            .unwrap()
+            .unwrap();
+        let name: &str = export.name.as_ref();
+        assert_eq!(found.base, module.base);
+        assert_eq!(name, "second");
+        assert_eq!(export.offset, 0x800);
+        assert_eq!(delta, 0x100);
+
+        // an address exactly at an export has no delta
+        let (_, export, delta) = prc
+            .nearest_export(module.base + 0x2000usize)
+            .unwrap()
+            .unwrap();
+        let name: &str = export.name.as_ref();
+        assert_eq!(name, "third");
+        assert_eq!(delta, 0);
+
+        // addresses in front of the first export do not resolve
+        assert!(prc
+            .nearest_export(module.base + 0x10usize)
+            .unwrap()
+            .is_none());
+    }
 }
@@ -173,6 +173,54 @@ pub trait PageChunks {
         self.mem_chunks_by(start_address, page_size as umem, split_fn)
     }
 
+    /// Create an aligned chunk iterator with a separate chunk size
+    ///
+    /// This behaves like `page_chunks` with `align` as the page size, but additionally
+    /// splits every aligned region into chunks of at most `chunk_size` bytes. The chunks
+    /// restart on every `align` boundary, thus no chunk will ever cross one.
+    ///
+    /// This is useful when scanning memory on large page boundaries while only reading
+    /// smaller windows at once, or vice versa.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_address` - starting address of the buffer
+    /// * `align` - alignment boundary chunks may not cross
+    /// * `chunk_size` - maximum size of a single chunk, zero is treated as one byte
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::prelude::{PageChunks, umem};
+    ///
+    /// let buffer = vec![0; 0x4000];
+    ///
+    /// // Step on 0x1000 boundaries, but read in 0x300 byte windows.
+    /// // Every aligned region is split into 5 chunks of 0x300, and one of 0x100.
+    /// let chunk_count = buffer
+    ///     .page_chunks_aligned(0.into(), 0x1000, 0x300)
+    ///     .count();
+    ///
+    /// assert_eq!(chunk_count, 24);
+    /// ```
+    fn page_chunks_aligned(
+        self,
+        start_address: Address,
+        align: usize,
+        chunk_size: usize,
+    ) -> AlignedChunkIterator<Self, TrueFunc<Self>>
+    where
+        Self: SplitAtIndex + Sized,
+    {
+        AlignedChunkIterator::new(
+            self,
+            start_address,
+            align as umem,
+            chunk_size as umem,
+            |_, _, _| true,
+        )
+    }
+
     fn mem_chunks_by<F: FnMut(Address, &Self, Option<&Self>) -> bool>(
         self,
         start_address: Address,
@@ -325,6 +373,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn pc_check_aligned() {
+        const CHUNK_SIZE: usize = 30;
+        let arr = [0_u8; 10 * PAGE_SIZE];
+
+        let mut total_len = 0;
+
+        for (addr, chunk) in arr.page_chunks_aligned(OFF.into(), PAGE_SIZE, CHUNK_SIZE) {
+            let base = addr.as_page_aligned(PAGE_SIZE);
+            let off = (addr.to_umem() - base.to_umem()) as usize;
+            assert!(chunk.len() <= CHUNK_SIZE);
+            assert!(off + chunk.len() <= PAGE_SIZE);
+            if addr != OFF.into() {
+                assert_eq!(off % CHUNK_SIZE, 0);
+            }
+            total_len += chunk.len();
+        }
+
+        assert_eq!(total_len, 10 * PAGE_SIZE);
+    }
+
+    #[test]
+    fn pc_check_aligned_zero_chunk() {
+        let arr = [0_u8; 16];
+        let chunks = arr.page_chunks_aligned(Address::null(), PAGE_SIZE, 0);
+        assert!(chunks.map(|(_, c)| c.len()).all(|len| len == 1));
+    }
+
     #[test]
     fn pc_check_empty() {
         let arr = [0_u8; 0];
@@ -487,3 +487,55 @@ impl<T: SplitAtIndex, FS: FnMut(Address, &T, Option<&T>) -> bool> Iterator
         }
     }
 }
+
+/// Iterator splitting a buffer into chunks of a fixed maximum size that never cross an
+/// alignment boundary.
+///
+/// Every chunk restarts at the previous `align` boundary, the last chunk of an aligned
+/// region may therefore be smaller than `chunk_size`.
+pub struct AlignedChunkIterator<T: SplitAtIndex, FS> {
+    inner: PageChunkIterator<T, FS>,
+    cur: Option<(Address, T)>,
+    align: umem,
+    chunk_size: umem,
+}
+
+impl<T: SplitAtIndex, FS> AlignedChunkIterator<T, FS> {
+    /// Creates a new iterator over `buf`, which is located at `start_address`.
+    ///
+    /// `check_split_fn` is forwarded to the underlying [`PageChunkIterator`] which splits
+    /// the buffer on `align` boundaries. A `chunk_size` of zero is treated as one byte.
+    pub fn new(
+        buf: T,
+        start_address: Address,
+        align: umem,
+        chunk_size: umem,
+        check_split_fn: FS,
+    ) -> Self {
+        Self {
+            inner: PageChunkIterator::new(buf, start_address, align, check_split_fn),
+            cur: None,
+            align,
+            chunk_size: chunk_size.max(1),
+        }
+    }
+}
+
+impl<T: SplitAtIndex, FS: FnMut(Address, &T, Option<&T>) -> bool> Iterator
+    for AlignedChunkIterator<T, FS>
+{
+    type Item = (Address, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (addr, buf) = self.cur.take().or_else(|| self.inner.next())?;
+
+        // chunks restart at every alignment boundary, so the split is relative to it
+        let base = addr.as_mem_aligned(self.align);
+        let off = addr.to_umem().wrapping_sub(base.to_umem()) % self.chunk_size;
+
+        let (head, tail) = (addr, buf).split_at(self.chunk_size - off);
+        self.cur = tail;
+        head
+    }
+}
@@ -25,6 +25,8 @@ pub type VtopRange = CTup2<Address, umem>;
 
 pub type MemoryRange = CTup3<Address, umem, PageType>;
 
+pub type PhysicalMemoryRange = CTup2<Address, umem>;
+
 pub trait WriteRawIterator<'a>: Iterator<Item = WriteDataRaw<'a>> + 'a {}
 impl<'a, T: Iterator<Item = WriteDataRaw<'a>> + 'a> WriteRawIterator<'a> for T {}
 
@@ -51,6 +53,8 @@ pub type WriteCallback<'a, 'b> = OpaqueCallback<'a, WriteData<'b>>;
 
 pub type MemoryRangeCallback<'a> = OpaqueCallback<'a, MemoryRange>;
 
+pub type PhysicalMemoryRangeCallback<'a> = OpaqueCallback<'a, PhysicalMemoryRange>;
+
 /// Data needed to perform memory operations.
 ///
 /// `inp` is an iterator containing
@@ -77,3 +77,331 @@ impl<'a, T: MemoryView> Drop for MemoryViewBatcher<'a, T> {
         let _ = self.commit_rw();
     }
 }
+
+/// Batched read of differently typed values
+///
+/// Every call to `add` appends a value of the given type to the result tuple. All values are
+/// read with a single call to [`MemoryView::read_raw_list`] once `finish` is invoked.
+///
+/// # Examples
+///
+/// ```
+/// use memflow::types::Address;
+/// use memflow::mem::{MemoryView, memory_view::TypedReadBatch};
+///
+/// fn read_header(mem: &mut impl MemoryView, addr: Address) {
+///     let (magic, size) = TypedReadBatch::new()
+///         .add::<u32>(addr)
+///         .add::<u64>(addr + 8)
+///         .finish(mem)
+///         .unwrap();
+///
+///     assert_eq!(magic, 0xfeed);
+///     assert_eq!(size, 0x1000);
+/// }
+///
+/// # use memflow::types::size;
+/// # use memflow::dummy::DummyOs;
+/// # use memflow::os::Process;
+/// # let mut buf = [0u8; 16];
+/// # buf[..4].copy_from_slice(&0xfeedu32.to_le_bytes());
+/// # buf[8..].copy_from_slice(&0x1000u64.to_le_bytes());
+/// # let mut proc = DummyOs::quick_process(size::mb(2), &buf);
+/// # let addr = proc.info().address;
+/// # read_header(&mut proc, addr);
+/// ```
+pub struct TypedReadBatch<L> {
+    addrs: Vec<Address>,
+    values: L,
+}
+
+impl TypedReadBatch<()> {
+    /// Creates an empty batch, values are queued with [`add`](TypedReadBatch::add)
+    pub fn new() -> Self {
+        Self {
+            addrs: vec![],
+            values: (),
+        }
+    }
+}
+
+impl Default for TypedReadBatch<()> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L> TypedReadBatch<L> {
+    /// Queues a read of a `N` at the given address
+    #[allow(clippy::should_implement_trait)]
+    pub fn add<N: Pod + Sized>(mut self, addr: Address) -> TypedReadBatch<L::Output>
+    where
+        L: ReadBatchPush<N>,
+    {
+        self.addrs.push(addr);
+        TypedReadBatch {
+            addrs: self.addrs,
+            values: self
+                .values
+                .push(unsafe { MaybeUninit::<N>::zeroed().assume_init() }),
+        }
+    }
+
+    /// Reads all queued values and returns them as a tuple
+    ///
+    /// In case of a partial read the failed values are zeroed out and
+    /// [`PartialError::PartialVirtualRead`] is returned containing all values.
+    pub fn finish(mut self, mem: &mut impl MemoryView) -> PartialResult<L>
+    where
+        L: ReadBatchTuple,
+    {
+        let mut list = Vec::with_capacity(self.addrs.len());
+        let mut addrs = self.addrs.iter();
+
+        self.values.for_each_bytes_mut(&mut |bytes| {
+            list.push(CTup2(*addrs.next().unwrap(), bytes.into()))
+        });
+
+        let ret = mem.read_raw_list(&mut list);
+        drop(list);
+
+        match ret {
+            Ok(_) => Ok(self.values),
+            Err(PartialError::Error(e)) => Err(PartialError::Error(e)),
+            Err(_) => Err(PartialError::PartialVirtualRead(self.values)),
+        }
+    }
+}
+
+/// Tuple of [`Pod`] values which can be read in a [`TypedReadBatch`]
+pub trait ReadBatchTuple {
+    fn for_each_bytes_mut<'a>(&'a mut self, func: &mut dyn FnMut(&'a mut [u8]));
+}
+
+/// Appends a value of type `N` at the end of a tuple
+pub trait ReadBatchPush<N> {
+    type Output;
+
+    fn push(self, val: N) -> Self::Output;
+}
+
+macro_rules! impl_read_batch_tuple {
+    ($($t:ident),*) => {
+        impl<$($t: Pod + Sized),*> ReadBatchTuple for ($($t,)*) {
+            #[allow(non_snake_case, unused_variables)]
+            fn for_each_bytes_mut<'a>(&'a mut self, func: &mut dyn FnMut(&'a mut [u8])) {
+                let ($($t,)*) = self;
+                $(func($t.as_bytes_mut());)*
+            }
+        }
+
+        impl<$($t,)* N> ReadBatchPush<N> for ($($t,)*) {
+            type Output = ($($t,)* N,);
+
+            #[allow(non_snake_case)]
+            fn push(self, val: N) -> Self::Output {
+                let ($($t,)*) = self;
+                ($($t,)* val,)
+            }
+        }
+    };
+}
+
+impl_read_batch_tuple!();
+impl_read_batch_tuple!(A);
+impl_read_batch_tuple!(A, B);
+impl_read_batch_tuple!(A, B, C);
+impl_read_batch_tuple!(A, B, C, D);
+impl_read_batch_tuple!(A, B, C, D, E);
+impl_read_batch_tuple!(A, B, C, D, E, F);
+impl_read_batch_tuple!(A, B, C, D, E, F, G);
+impl_read_batch_tuple!(A, B, C, D, E, F, G, H);
+impl_read_batch_tuple!(A, B, C, D, E, F, G, H, I);
+impl_read_batch_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_read_batch_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+
+/// Type of a value read by a [`DynamicReadBatch`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FieldKind {
+    U8,
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+    F32,
+    F64,
+    /// Raw bytes of the given length
+    Bytes(usize),
+}
+
+/// Value read by a [`DynamicReadBatch`]
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldValue {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    F32(f32),
+    F64(f64),
+    Bytes(Vec<u8>),
+}
+
+impl FieldValue {
+    /// Creates a zeroed value of the given kind
+    pub fn zeroed(kind: FieldKind) -> Self {
+        match kind {
+            FieldKind::U8 => FieldValue::U8(0),
+            FieldKind::U16 => FieldValue::U16(0),
+            FieldKind::U32 => FieldValue::U32(0),
+            FieldKind::U64 => FieldValue::U64(0),
+            FieldKind::I8 => FieldValue::I8(0),
+            FieldKind::I16 => FieldValue::I16(0),
+            FieldKind::I32 => FieldValue::I32(0),
+            FieldKind::I64 => FieldValue::I64(0),
+            FieldKind::F32 => FieldValue::F32(0.0),
+            FieldKind::F64 => FieldValue::F64(0.0),
+            FieldKind::Bytes(len) => FieldValue::Bytes(vec![0; len]),
+        }
+    }
+
+    fn as_bytes_mut(&mut self) -> &mut [u8] {
+        match self {
+            FieldValue::U8(v) => v.as_bytes_mut(),
+            FieldValue::U16(v) => v.as_bytes_mut(),
+            FieldValue::U32(v) => v.as_bytes_mut(),
+            FieldValue::U64(v) => v.as_bytes_mut(),
+            FieldValue::I8(v) => v.as_bytes_mut(),
+            FieldValue::I16(v) => v.as_bytes_mut(),
+            FieldValue::I32(v) => v.as_bytes_mut(),
+            FieldValue::I64(v) => v.as_bytes_mut(),
+            FieldValue::F32(v) => v.as_bytes_mut(),
+            FieldValue::F64(v) => v.as_bytes_mut(),
+            FieldValue::Bytes(v) => v.as_mut_slice(),
+        }
+    }
+}
+
+/// Batched read of values whose types are only known at runtime
+///
+/// This is the dynamic counterpart of [`TypedReadBatch`]. The values are returned
+/// in the order they were added.
+///
+/// # Examples
+///
+/// ```
+/// use memflow::types::Address;
+/// use memflow::mem::MemoryView;
+/// use memflow::mem::memory_view::batcher::{DynamicReadBatch, FieldKind, FieldValue};
+///
+/// fn read_header(mem: &mut impl MemoryView, addr: Address) {
+///     let mut batch = DynamicReadBatch::new();
+///     batch.add(addr, FieldKind::U32).add(addr + 8, FieldKind::Bytes(2));
+///
+///     assert_eq!(
+///         batch.finish(mem).unwrap(),
+///         vec![FieldValue::U32(0xfeed), FieldValue::Bytes(vec![1, 2])]
+///     );
+/// }
+///
+/// # use memflow::types::size;
+/// # use memflow::dummy::DummyOs;
+/// # use memflow::os::Process;
+/// # let mut buf = [0u8; 16];
+/// # buf[..4].copy_from_slice(&0xfeedu32.to_le_bytes());
+/// # buf[8..10].copy_from_slice(&[1, 2]);
+/// # let mut proc = DummyOs::quick_process(size::mb(2), &buf);
+/// # let addr = proc.info().address;
+/// # read_header(&mut proc, addr);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DynamicReadBatch {
+    fields: Vec<(Address, FieldValue)>,
+}
+
+impl DynamicReadBatch {
+    /// Creates an empty batch, values are queued with [`add`](DynamicReadBatch::add)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a read of a value of the given kind at the given address
+    pub fn add(&mut self, addr: Address, kind: FieldKind) -> &mut Self {
+        self.fields.push((addr, FieldValue::zeroed(kind)));
+        self
+    }
+
+    /// Reads all queued values
+    ///
+    /// In case of a partial read the failed values are zeroed out and
+    /// [`PartialError::PartialVirtualRead`] is returned containing all values.
+    pub fn finish(mut self, mem: &mut impl MemoryView) -> PartialResult<Vec<FieldValue>> {
+        let mut list = self
+            .fields
+            .iter_mut()
+            .map(|(addr, value)| CTup2(*addr, value.as_bytes_mut().into()))
+            .collect::<Vec<_>>();
+
+        let ret = mem.read_raw_list(&mut list);
+        drop(list);
+
+        let values = self.fields.into_iter().map(|(_, value)| value).collect();
+
+        match ret {
+            Ok(_) => Ok(values),
+            Err(PartialError::Error(e)) => Err(PartialError::Error(e)),
+            Err(_) => Err(PartialError::PartialVirtualRead(values)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dummy::DummyOs;
+    use crate::os::Process;
+    use crate::types::size;
+
+    #[test]
+    fn typed_read_partial() {
+        let mut proc = DummyOs::quick_process(size::mb(2), &0x1234u32.to_le_bytes());
+        let addr = proc.info().address;
+        let end = addr + size::mb(2);
+
+        // the second value crosses the end of the mapping
+        let ret = TypedReadBatch::new()
+            .add::<u32>(addr)
+            .add::<u64>(end - 4_usize)
+            .finish(&mut proc);
+
+        assert_eq!(ret, Err(PartialError::PartialVirtualRead((0x1234, 0))));
+    }
+
+    #[test]
+    fn dynamic_read_partial() {
+        let mut proc = DummyOs::quick_process(size::mb(2), &0x1234u32.to_le_bytes());
+        let addr = proc.info().address;
+        let end = addr + size::mb(2);
+
+        let mut batch = DynamicReadBatch::new();
+        batch
+            .add(addr, FieldKind::U32)
+            .add(end, FieldKind::U64)
+            .add(end - 2_usize, FieldKind::Bytes(4));
+
+        assert_eq!(
+            batch.finish(&mut proc),
+            Err(PartialError::PartialVirtualRead(vec![
+                FieldValue::U32(0x1234),
+                FieldValue::U64(0),
+                FieldValue::Bytes(vec![0; 4]),
+            ]))
+        );
+    }
+}
@@ -422,6 +422,24 @@ mod tests {
         assert_eq!(read_buf, write_buf); // compare buffers
     }
 
+    #[test]
+    fn range_read_seek() {
+        let mut phys_mem = dummy_phys_mem();
+        phys_mem
+            .phys_write(Address::from(0x1004).into(), &[0xAu8, 0xB, 0xC, 0xD])
+            .unwrap();
+
+        let mut view = phys_mem.phys_view();
+        let mut cursor = view.range_cursor(Address::from(0x1000), 0x100);
+
+        let mut read_buf = [0u8; 4];
+        assert_eq!(cursor.seek(SeekFrom::Start(4)).unwrap(), 4);
+        assert_eq!(cursor.read(&mut read_buf).unwrap(), 4);
+        assert_eq!(read_buf, [0xA, 0xB, 0xC, 0xD]);
+
+        assert_eq!(cursor.seek(SeekFrom::End(-4)).unwrap(), 0x100 - 4);
+    }
+
     fn dummy_virt_mem() -> (
         VirtualDma<DummyMemory, DirectTranslate, X86VirtualTranslate>,
         Address,
@@ -13,7 +13,7 @@ pub mod remap_view;
 pub mod cursor;
 
 pub use arch_overlay::ArchOverlayView;
-pub use batcher::MemoryViewBatcher;
+pub use batcher::{DynamicReadBatch, FieldKind, FieldValue, MemoryViewBatcher, TypedReadBatch};
 pub use remap_view::RemapView;
 
 #[cfg(feature = "std")]
@@ -181,6 +181,101 @@ pub trait MemoryView: Send {
         self.read_raw_into(addr, &mut buf).map_data(|_| buf)
     }
 
+    /// Reads into `out` and returns the number of leading bytes that were read successfully.
+    ///
+    /// [`PartialError::PartialVirtualRead`] does not tell which parts of a read failed.
+    /// This function can be used to find out whether a prefix of the buffer (e.g. the first
+    /// fields of a structure spanning into an unmapped page) is valid.
+    /// All bytes that could not be read are zeroed out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::types::{size, Address};
+    /// use memflow::mem::MemoryView;
+    ///
+    /// fn read_tail(mem: &mut impl MemoryView, addr: Address) {
+    ///     // the last 4 bytes are outside of the mapped region
+    ///     let mut buf = [0u8; 8];
+    ///     let valid_len = mem
+    ///         .read_raw_into_valid_len(addr + size::mb(2) - 4_usize, &mut buf)
+    ///         .unwrap();
+    ///     assert_eq!(valid_len, 4);
+    /// }
+    ///
+    /// # use memflow::dummy::DummyOs;
+    /// # use memflow::os::Process;
+    /// # let mut proc = DummyOs::quick_process(size::mb(2), &[]);
+    /// # let addr = proc.info().address;
+    /// # read_tail(&mut proc, addr);
+    /// ```
+    #[skip_func]
+    fn read_raw_into_valid_len(&mut self, addr: Address, out: &mut [u8]) -> Result<usize>
+    where
+        Self: Sized,
+    {
+        let mut valid_len = out.len();
+
+        // the meta address holds the offset within `out`
+        let callback = &mut |CTup2(meta, mut d): ReadData| {
+            d.iter_mut().for_each(|b| *b = 0);
+            valid_len = core::cmp::min(valid_len, meta.to_umem() as usize);
+            true
+        };
+
+        MemOps::with_raw(
+            std::iter::once(CTup3(addr, Address::null(), out.into())),
+            None,
+            Some(&mut callback.into()),
+            |data| self.read_raw_iter(data),
+        )?;
+
+        Ok(valid_len)
+    }
+
+    /// Reads `len` bytes and formats them as a hex dump.
+    ///
+    /// Bytes which could not be read are printed as `??`. The address width is based on
+    /// `MemoryViewMetadata::arch_bits`. See [`hexdump_partial`](crate::types::util::hexdump_partial)
+    /// for the format.
+    #[skip_func]
+    fn hexdump(&mut self, addr: Address, len: usize) -> Result<String>
+    where
+        Self: Sized,
+    {
+        let mut buf = vec![0u8; len];
+        let mut valid = vec![true; len];
+
+        // the meta address holds the offset within `buf`
+        let callback = &mut |CTup2(meta, d): ReadData| {
+            let start = meta.to_umem() as usize;
+            valid[start..start + d.len()]
+                .iter_mut()
+                .for_each(|v| *v = false);
+            true
+        };
+
+        MemOps::with_raw(
+            std::iter::once(CTup3(addr, Address::null(), buf.as_mut_slice().into())),
+            None,
+            Some(&mut callback.into()),
+            |data| self.read_raw_iter(data),
+        )?;
+
+        let data = buf
+            .into_iter()
+            .zip(valid)
+            .map(|(b, valid)| if valid { Some(b) } else { None })
+            .collect::<Vec<_>>();
+
+        // fall back to 64-bit addresses if the architecture of the view is unknown
+        let addr_size = match self.metadata().arch_bits as usize / 8 {
+            0 => 8,
+            size => size,
+        };
+        Ok(crate::types::util::hexdump_partial(addr, &data, addr_size))
+    }
+
     #[skip_func]
     fn read_into<T: Pod + ?Sized>(&mut self, addr: Address, out: &mut T) -> PartialResult<()>
     where
@@ -200,6 +295,59 @@ pub trait MemoryView: Send {
         self.read_into(addr, &mut obj).map_data(|_| obj)
     }
 
+    /// Reads `count` consecutive elements of type `T` in a single transfer.
+    ///
+    /// If `swap` is set each element is byte swapped after the read. This is useful for
+    /// reading arrays from targets with a byte order that differs from the host.
+    ///
+    /// In case of a partial read the failed parts are zeroed out and [`PartialError::PartialVirtualRead`]
+    /// is returned containing all elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::types::Address;
+    /// use memflow::mem::MemoryView;
+    ///
+    /// fn read_big_endian(mem: &mut impl MemoryView, addr: Address) {
+    ///     let values = mem.read_pod_array::<u32>(addr, 2, true).unwrap();
+    ///     assert_eq!(values, vec![1, 2]);
+    /// }
+    ///
+    /// # use memflow::types::size;
+    /// # use memflow::dummy::DummyOs;
+    /// # use memflow::os::Process;
+    /// # let mut proc = DummyOs::quick_process(size::mb(2), &[0, 0, 0, 1, 0, 0, 0, 2]);
+    /// # let addr = proc.info().address;
+    /// # read_big_endian(&mut proc, addr);
+    /// ```
+    #[skip_func]
+    fn read_pod_array<T: Pod + ByteSwap>(
+        &mut self,
+        addr: Address,
+        count: usize,
+        swap: bool,
+    ) -> PartialResult<Vec<T>>
+    where
+        Self: Sized,
+    {
+        let mut out = (0..count)
+            .map(|_| unsafe { MaybeUninit::<T>::zeroed().assume_init() })
+            .collect::<Vec<_>>();
+
+        let ret = self.read_raw_into(addr, out.as_mut_slice().as_bytes_mut());
+
+        if swap {
+            out.iter_mut().for_each(|v| v.byte_swap());
+        }
+
+        match ret {
+            Ok(_) => Ok(out),
+            Err(PartialError::Error(e)) => Err(PartialError::Error(e)),
+            Err(_) => Err(PartialError::PartialVirtualRead(out)),
+        }
+    }
+
     // TODO: allow cglue to somehow pass MaybeUninit to the IntError
     #[skip_func]
     fn read_addr32(&mut self, addr: Address) -> PartialResult<Address>
@@ -217,12 +365,19 @@ pub trait MemoryView: Send {
         self.read::<u64>(addr).map_data(|d| d.into())
     }
 
+    /// Reads a pointer sized address of the given architecture.
+    ///
+    /// The value is byte swapped if the endianess of `arch` differs from the host.
     #[skip_func]
     fn read_addr_arch(&mut self, arch: ArchitectureObj, addr: Address) -> PartialResult<Address>
     where
         Self: Sized,
     {
+        let swap = (arch.endianess() == Endianess::LittleEndian) != cfg!(target_endian = "little");
+
         match arch.bits() {
+            64 if swap => self.read::<u64>(addr).map_data(|d| d.swap_bytes().into()),
+            32 if swap => self.read::<u32>(addr).map_data(|d| d.swap_bytes().into()),
             64 => self.read_addr64(addr),
             32 => self.read_addr32(addr),
             _ => Err(PartialError::Error(Error(
@@ -255,6 +410,89 @@ pub trait MemoryView: Send {
         self.read(ptr.into())
     }
 
+    /// Reads the targets of multiple pointers in a single batch.
+    ///
+    /// All reads are submitted at once, which allows the underlying memory to
+    /// translate and coalesce them. The results are returned in the same order as `ptrs`.
+    /// Values which could not be read are zeroed out and returned as
+    /// [`PartialError::PartialVirtualRead`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::types::{Address, Pointer64};
+    /// use memflow::mem::MemoryView;
+    ///
+    /// fn read_table(mem: &mut impl MemoryView, addr: Address) {
+    ///     let ptrs = [Pointer64::<u32>::from(addr), Pointer64::from(addr + 4)];
+    ///     let values = mem.read_ptr_batch(&ptrs);
+    ///     assert_eq!(values[0], Ok(1));
+    ///     assert_eq!(values[1], Ok(2));
+    /// }
+    ///
+    /// # use memflow::types::size;
+    /// # use memflow::dummy::DummyOs;
+    /// # use memflow::os::Process;
+    /// # let mut proc = DummyOs::quick_process(size::mb(2), &[1, 0, 0, 0, 2, 0, 0, 0]);
+    /// # let addr = proc.info().address;
+    /// # read_table(&mut proc, addr);
+    /// ```
+    #[skip_func]
+    fn read_ptr_batch<U: PrimitiveAddress, T: Pod + Sized>(
+        &mut self,
+        ptrs: &[Pointer<U, T>],
+    ) -> Vec<PartialResult<T>>
+    where
+        Self: Sized,
+    {
+        let mut out = (0..ptrs.len())
+            .map(|_| unsafe { MaybeUninit::<T>::zeroed().assume_init() })
+            .collect::<Vec<_>>();
+        let mut failed = vec![false; ptrs.len()];
+
+        // the meta address encodes the offset within `out`, which survives page splits
+        let elem_size = core::cmp::max(core::mem::size_of::<T>(), 1) as umem;
+
+        let callback = &mut |CTup2(meta, mut d): ReadData| {
+            failed[(meta.to_umem() / elem_size) as usize] = true;
+            d.iter_mut().for_each(|b| *b = 0);
+            true
+        };
+
+        let iter = ptrs
+            .iter()
+            .zip(out.iter_mut())
+            .enumerate()
+            .map(|(i, (ptr, out))| {
+                CTup3(
+                    ptr.address(),
+                    Address::from(i as umem * elem_size),
+                    out.as_bytes_mut().into(),
+                )
+            });
+
+        let ret = MemOps::with_raw(iter, None, Some(&mut callback.into()), |data| {
+            self.read_raw_iter(data)
+        });
+
+        match ret {
+            Ok(_) => out
+                .into_iter()
+                .zip(failed)
+                .map(|(v, failed)| {
+                    if failed {
+                        Err(PartialError::PartialVirtualRead(v))
+                    } else {
+                        Ok(v)
+                    }
+                })
+                .collect(),
+            Err(e) => (0..ptrs.len())
+                .map(|_| Err(PartialError::Error(e)))
+                .collect(),
+        }
+    }
+
     // Write helpers
 
     /// Write arbitrary amount of data.
@@ -358,6 +596,99 @@ pub trait MemoryView: Send {
         self.write_raw_list(&[CTup2(addr, data.into())])
     }
 
+    /// Copies `len` bytes from this memory view into another one.
+    ///
+    /// The data is streamed in chunks of at most `chunk_size` bytes which are aligned to the
+    /// source address, usually `chunk_size` is the page size of the source architecture.
+    /// Chunks that cannot be read are skipped and not written to the destination. Each view
+    /// performs its own translation, so the source and destination may be address spaces of
+    /// different processes (or architectures).
+    ///
+    /// The returned [`CopyReport`] holds the number of copied bytes and the ranges that failed.
+    /// If any chunk could not be read, [`PartialError::PartialVirtualRead`] holds the report.
+    /// If only writes failed [`PartialError::PartialVirtualWrite`] is returned instead. Bytes of
+    /// a partially failed write are not counted as copied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::types::{size, Address};
+    /// use memflow::mem::MemoryView;
+    ///
+    /// fn copy(src: &mut impl MemoryView, src_addr: Address, dst: &mut impl MemoryView, dst_addr: Address) {
+    ///     // the destination range crosses a page boundary
+    ///     let report = src
+    ///         .copy_raw_to(src_addr, dst, dst_addr + 0xffe_usize, 4, size::kb(4))
+    ///         .unwrap();
+    ///     assert_eq!(report.copied, 4);
+    ///
+    ///     let mut buf = [0u8; 4];
+    ///     dst.read_raw_into(dst_addr + 0xffe_usize, &mut buf).unwrap();
+    ///     assert_eq!(buf, [1, 2, 3, 4]);
+    /// }
+    ///
+    /// # use memflow::dummy::DummyOs;
+    /// # use memflow::os::Process;
+    /// # let mut src = DummyOs::quick_process(size::mb(2), &[1, 2, 3, 4]);
+    /// # let src_addr = src.info().address;
+    /// # let mut dst = DummyOs::quick_process(size::mb(2), &[]);
+    /// # let dst_addr = dst.info().address;
+    /// # copy(&mut src, src_addr, &mut dst, dst_addr);
+    /// ```
+    #[skip_func]
+    fn copy_raw_to(
+        &mut self,
+        addr: Address,
+        dst: &mut impl MemoryView,
+        dst_addr: Address,
+        len: usize,
+        chunk_size: usize,
+    ) -> PartialResult<CopyReport>
+    where
+        Self: Sized,
+    {
+        let chunk_size = chunk_size.max(1);
+        let mut buf = vec![0u8; std::cmp::min(chunk_size, len)];
+
+        let mut report = CopyReport::default();
+
+        let mut offset = 0;
+        while offset < len {
+            let cur = addr + offset;
+            let chunk_len = std::cmp::min(
+                chunk_size - (cur.to_umem() as usize % chunk_size),
+                len - offset,
+            );
+            let chunk = &mut buf[..chunk_len];
+
+            match self.read_raw_into(cur, chunk) {
+                Ok(_) => match dst.write_raw(dst_addr + offset, chunk) {
+                    Ok(_) => report.copied += chunk_len,
+                    Err(PartialError::Error(e)) => return Err(PartialError::Error(e)),
+                    Err(_) => CopyReport::push_range(
+                        &mut report.write_failed,
+                        AddressRange::with_size(dst_addr + offset, chunk_len as umem),
+                    ),
+                },
+                Err(PartialError::Error(e)) => return Err(PartialError::Error(e)),
+                Err(_) => CopyReport::push_range(
+                    &mut report.read_failed,
+                    AddressRange::with_size(cur, chunk_len as umem),
+                ),
+            }
+
+            offset += chunk_len;
+        }
+
+        if !report.read_failed.is_empty() {
+            Err(PartialError::PartialVirtualRead(report))
+        } else if !report.write_failed.is_empty() {
+            Err(PartialError::PartialVirtualWrite(report))
+        } else {
+            Ok(report)
+        }
+    }
+
     #[skip_func]
     fn write<T: Pod + ?Sized>(&mut self, addr: Address, data: &T) -> PartialResult<()>
     where
@@ -446,6 +777,175 @@ pub trait MemoryView: Send {
         self.read_char_string_n(addr, 4096)
     }
 
+    /// Reads bytes until the given delimiter is found.
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - target address to read from
+    /// * `delimiter` - byte sequence that terminates the data
+    /// * `max` - maximum number of bytes to read
+    ///
+    /// # Remarks:
+    ///
+    /// The memory is read in page-sized increments. The returned buffer does not contain the delimiter.
+    /// If the delimiter is not found within `max` bytes this function will return an error.
+    /// A `PartialData` error is returned if a page can only be read partially, as the bytes that
+    /// failed to read would otherwise be mistaken for a null delimiter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::types::Address;
+    /// use memflow::mem::MemoryView;
+    ///
+    /// fn read_line(mem: &mut impl MemoryView, addr: Address) {
+    ///     // the delimiter straddles the page boundary
+    ///     mem.write_raw(addr + 0xffd_usize, b"ab\r\ncd").unwrap();
+    ///
+    ///     let line = mem.read_until(addr + 0xffd_usize, b"\r\n", 0x100).unwrap();
+    ///     assert_eq!(line, b"ab");
+    /// }
+    ///
+    /// # use memflow::types::size;
+    /// # use memflow::dummy::DummyOs;
+    /// # use memflow::os::Process;
+    /// # let mut proc = DummyOs::quick_process(size::mb(2), &[]);
+    /// # let addr = proc.info().address;
+    /// # read_line(&mut proc, addr);
+    /// ```
+    #[skip_func]
+    fn read_until(&mut self, addr: Address, delimiter: &[u8], max: usize) -> Result<Vec<u8>>
+    where
+        Self: Sized,
+    {
+        if delimiter.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let page_size = size::kb(4);
+        let mut buf = vec![];
+
+        while buf.len() < max {
+            let cur = addr + buf.len();
+            let start = buf.len();
+
+            // read up to the next page boundary
+            let chunk_len = std::cmp::min(
+                page_size - (cur.to_umem() as usize % page_size),
+                max - start,
+            );
+            buf.resize(start + chunk_len, 0);
+            self.read_raw_into(cur, &mut buf[start..])?;
+
+            // include the tail of the previous chunk to find delimiters crossing the boundary
+            let search_start = start.saturating_sub(delimiter.len() - 1);
+            if let Some(n) = buf[search_start..]
+                .windows(delimiter.len())
+                .position(|w| w == delimiter)
+            {
+                buf.truncate(search_start + n);
+                return Ok(buf);
+            }
+        }
+
+        Err(Error(ErrorOrigin::VirtualMemory, ErrorKind::OutOfBounds))
+    }
+
+    /// Reads a UTF-16 encoded string with a length of `byte_len` bytes from the target.
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - target address of the string buffer
+    /// * `byte_len` - length of the string in bytes
+    ///
+    /// # Remarks:
+    ///
+    /// The string does not have to be null-terminated. This is useful for reading counted strings
+    /// where the length is stored separately from the buffer (e.g. `UNICODE_STRING`).
+    /// The byte order is taken from the [`metadata`](Self::metadata) of this view.
+    /// Invalid surrogate pairs are replaced with `U+FFFD`.
+    /// A `PartialData` error is returned if the string can only be read partially, instead
+    /// of decoding the bytes that failed to read as null characters.
+    #[skip_func]
+    fn read_utf16_string(&mut self, addr: Address, byte_len: usize) -> PartialResult<String>
+    where
+        Self: Sized,
+    {
+        let mut buf = vec![0; byte_len - byte_len % 2];
+        self.read_raw_into(addr, &mut buf).map_err(Error::from)?;
+
+        let little_endian = self.metadata().little_endian;
+        let chars = buf
+            .chunks_exact(2)
+            .map(|c| {
+                if little_endian {
+                    u16::from_le_bytes([c[0], c[1]])
+                } else {
+                    u16::from_be_bytes([c[0], c[1]])
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Ok(String::from_utf16_lossy(&chars))
+    }
+
+    /// Reads a null-terminated string of up to `max` bytes and detects its encoding.
+    ///
+    /// This is useful for strings of unknown or undocumented encoding. The string is decoded as UTF-16
+    /// (in the byte order of this view) if it looks like wide text, and as a narrow string otherwise.
+    /// See [`decode_string_auto`](crate::types::util::decode_string_auto) for the heuristic.
+    /// The memory is read in growing chunks until a terminator is found or `max` bytes were read.
+    #[skip_func]
+    fn read_string_auto(&mut self, addr: Address, max: usize) -> PartialResult<String>
+    where
+        Self: Sized,
+    {
+        let little_endian = self.metadata().little_endian;
+
+        let mut buf = vec![0; std::cmp::min(32, max)];
+
+        let mut last_n = 0;
+
+        loop {
+            let (_, right) = buf.split_at_mut(last_n);
+
+            self.read_raw_into(addr + last_n, right).data_part()?;
+
+            // the encoding is decided on the first few characters, which are part of the first read
+            let terminated = if crate::types::util::string_auto_is_wide(&buf, little_endian) {
+                buf.chunks_exact(2).any(|c| c[0] == 0 && c[1] == 0)
+            } else {
+                buf.contains(&0)
+            };
+            if terminated || buf.len() >= max {
+                break;
+            }
+            last_n = buf.len();
+
+            buf.resize(std::cmp::min(buf.len() * 2, max), 0);
+        }
+
+        Ok(crate::types::util::decode_string_auto(&buf, little_endian))
+    }
+
+    /// Reads a pointer of the given architecture and then reads a UTF-16 encoded string
+    /// with a length of `byte_len` bytes from where it points to.
+    ///
+    /// See [`read_utf16_string`](Self::read_utf16_string) for more information.
+    #[skip_func]
+    fn read_utf16_string_ptr_arch(
+        &mut self,
+        arch: ArchitectureObj,
+        addr: Address,
+        byte_len: usize,
+    ) -> PartialResult<String>
+    where
+        Self: Sized,
+    {
+        let buffer = self.read_addr_arch(arch, addr).data_part()?;
+        self.read_utf16_string(buffer, byte_len)
+    }
+
     // TODO: batcher
 
     #[cfg(feature = "std")]
@@ -484,6 +984,22 @@ pub trait MemoryView: Send {
         MemoryCursor::at(self, address)
     }
 
+    /// Creates a cursor over the `size` bytes starting at `base`.
+    ///
+    /// Cursor positions are relative to `base` and seeking from the end is relative to the
+    /// end of the range. This allows handing a mapped image to parsers which expect
+    /// [`Read`](std::io::Read) + [`Seek`](std::io::Seek) objects.
+    #[cfg(feature = "std")]
+    #[skip_func]
+    fn range_cursor(&mut self, base: Address, size: umem) -> MemoryCursor<RemapView<Fwd<&mut Self>>>
+    where
+        Self: Sized,
+    {
+        let mut mem_map = MemoryMap::new();
+        mem_map.push_remap(Address::null(), size, base);
+        MemoryCursor::new(self.remap_view(mem_map))
+    }
+
     #[skip_func]
     fn batcher(&mut self) -> MemoryViewBatcher<Self>
     where
@@ -556,3 +1072,117 @@ pub struct MemoryViewMetadata {
     pub little_endian: bool,
     pub arch_bits: u8,
 }
+
+/// Outcome of [`MemoryView::copy_raw_to`]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CopyReport {
+    /// Number of bytes that were copied
+    pub copied: usize,
+    /// Source ranges that could not be read, and were therefore not copied
+    pub read_failed: Vec<AddressRange>,
+    /// Destination ranges that could not be written
+    pub write_failed: Vec<AddressRange>,
+}
+
+impl CopyReport {
+    /// Appends `range`, merging it with the last range if they are adjacent
+    fn push_range(ranges: &mut Vec<AddressRange>, range: AddressRange) {
+        match ranges.last_mut() {
+            Some(last) if last.end == range.start => last.end = range.end,
+            _ => ranges.push(range),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dummy::DummyOs;
+    use crate::os::Process;
+
+    #[test]
+    fn copy_raw_to_partial() {
+        let mut src = DummyOs::quick_process(size::mb(2), &[1; 0x10]);
+        let src_end = src.info().address + size::mb(2);
+        let mut dst = DummyOs::quick_process(size::mb(2), &[]);
+        let dst_addr = dst.info().address;
+
+        src.write_raw(src_end - 0x10_usize, &[2; 0x10]).unwrap();
+
+        // the last 0x10 bytes of the source are readable, the following 0x1000 are not
+        let ret = src.copy_raw_to(src_end - 0x10_usize, &mut dst, dst_addr, 0x1010, 0x800);
+
+        assert_eq!(
+            ret,
+            Err(PartialError::PartialVirtualRead(CopyReport {
+                copied: 0x10,
+                read_failed: vec![AddressRange::with_size(src_end, 0x1000)],
+                write_failed: vec![],
+            }))
+        );
+
+        let mut buf = [0u8; 0x10];
+        dst.read_raw_into(dst_addr, &mut buf).unwrap();
+        assert_eq!(buf, [2; 0x10]);
+    }
+
+    #[test]
+    fn read_until_partial() {
+        let mut proc = DummyOs::quick_process(size::mb(2), &[]);
+        let end = proc.info().address + size::mb(2);
+
+        // the last 0x10 bytes of the mapping do not contain the delimiter,
+        // the zeroed tail of the partial read must not be mistaken for it
+        proc.write_raw(end - 0x10_usize, &[1; 0x10]).unwrap();
+        assert_eq!(
+            proc.read_until(end - 0x10_usize, &[0], 0x100),
+            Err(Error(ErrorOrigin::Memory, ErrorKind::PartialData))
+        );
+    }
+
+    #[test]
+    fn read_utf16_string_partial() {
+        let mut proc = DummyOs::quick_process(size::mb(2), &[]);
+        let end = proc.info().address + size::mb(2);
+
+        proc.write_raw(end - 0x4_usize, &[b'a', 0, b'b', 0])
+            .unwrap();
+        assert_eq!(
+            proc.read_utf16_string(end - 0x4_usize, 4),
+            Ok("ab".to_string())
+        );
+        assert_eq!(
+            proc.read_utf16_string(end - 0x4_usize, 8),
+            Err(PartialError::Error(Error(
+                ErrorOrigin::Memory,
+                ErrorKind::PartialData
+            )))
+        );
+    }
+
+    #[test]
+    fn read_string_auto_chunks() {
+        let wide = "memflow is a library"
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect::<Vec<_>>();
+
+        let mut buf = b"narrow\0".to_vec();
+        buf.resize(0x100, 0);
+        buf.extend_from_slice(&wide);
+
+        let mut proc = DummyOs::quick_process(size::mb(2), &buf);
+        let addr = proc.info().address;
+
+        assert_eq!(proc.read_string_auto(addr, 0x100), Ok("narrow".to_string()));
+        // the wide string spans multiple chunks
+        assert_eq!(
+            proc.read_string_auto(addr + 0x100_usize, 0x100),
+            Ok("memflow is a library".to_string())
+        );
+        assert_eq!(
+            proc.read_string_auto(addr + 0x100_usize, 0xe),
+            Ok("memflow".to_string())
+        );
+    }
+}
@@ -15,7 +15,10 @@ pub mod virt_mem;
 pub mod virt_translate;
 
 pub use mem_map::{MemoryMap, PhysicalMemoryMapping};
-pub use phys_mem::{CachedPhysicalMemory, PhysicalMemory, PhysicalMemoryMetadata};
+pub use phys_mem::{
+    AlignedPhysicalMemory, CachedPhysicalMemory, ChunkedPhysicalMemory, PhysicalMemory,
+    PhysicalMemoryMetadata,
+};
 #[cfg(feature = "std")]
 pub use phys_mem::{DelayedPhysicalMemory, PhysicalMemoryMetrics};
 pub use virt_mem::VirtualDma;
@@ -26,7 +29,7 @@ pub use virt_translate::{
     VirtualTranslate3, VtopFailureCallback, VtopOutputCallback,
 };
 
-pub use memory_view::{MemoryView, MemoryViewMetadata};
+pub use memory_view::{CopyReport, MemoryView, MemoryViewMetadata};
 
 #[cfg(feature = "std")]
 pub use memory_view::MemoryCursor;
@@ -0,0 +1,239 @@
+use crate::cglue::*;
+use crate::error::Result;
+use crate::iter::SplitAtIndex;
+use crate::mem::{
+    opt_call, MemOps, PhysicalMemory, PhysicalMemoryMapping, PhysicalMemoryMetadata,
+    PhysicalMemoryRangeCallback, PhysicalReadData, PhysicalReadMemOps, PhysicalWriteMemOps,
+    ReadData,
+};
+use crate::types::{umem, Address, PhysicalAddress};
+
+use std::prelude::v1::*;
+
+/// The aligned middleware rounds reads up to a fixed alignment.
+///
+/// Some connectors (e.g. FPGA based DMA devices) perform a lot better when reads are aligned
+/// to their transfer granularity. This middleware reads the aligned superset of every read
+/// and only returns the requested part of it. Writes are forwarded unchanged.
+///
+/// Wrapping a [`CachedPhysicalMemory`](crate::mem::CachedPhysicalMemory) with this middleware
+/// will also cache the over-read parts, so that subsequent reads nearby are served from the cache.
+///
+/// Since this middleware implements [`PhysicalMemory`] it can be used as a replacement
+/// in all structs and functions that require the [`PhysicalMemory`] trait.
+#[derive(Clone)]
+pub struct AlignedPhysicalMemory<T> {
+    mem: T,
+    align: umem,
+}
+
+impl<T: PhysicalMemory> AlignedPhysicalMemory<T> {
+    /// Constructs a new middleware with the given read alignment.
+    ///
+    /// # Examples
+    /// ```
+    /// # const MAGIC_VALUE: u64 = 0x23bd_318f_f3a3_5821;
+    /// use memflow::types::size;
+    /// use memflow::mem::{PhysicalMemory, AlignedPhysicalMemory, MemoryView};
+    ///
+    /// fn build<T: PhysicalMemory>(mem: T) -> T {
+    ///     let mut middleware = AlignedPhysicalMemory::new(mem, 0x80);
+    ///
+    ///     // the read is performed on the aligned range 0x0..0x80
+    ///     let value: u64 = middleware.phys_view().read(0x10.into()).unwrap();
+    ///     assert_eq!(value, MAGIC_VALUE);
+    ///
+    ///     // retrieve ownership of mem and return it back
+    ///     middleware.into_inner()
+    /// }
+    /// # use memflow::dummy::DummyMemory;
+    /// # let mut mem = DummyMemory::new(size::mb(4));
+    /// # mem.phys_write(0x10.into(), &MAGIC_VALUE).unwrap();
+    /// # build(mem);
+    /// ```
+    pub fn new(mem: T, align: usize) -> Self {
+        Self {
+            mem,
+            align: core::cmp::max(align, 1) as umem,
+        }
+    }
+
+    /// Consumes self and returns the containing memory object.
+    pub fn into_inner(self) -> T {
+        self.mem
+    }
+}
+
+// forward PhysicalMemory trait fncs
+impl<T: PhysicalMemory> PhysicalMemory for AlignedPhysicalMemory<T> {
+    fn phys_read_raw_iter(
+        &mut self,
+        MemOps {
+            inp,
+            mut out,
+            mut out_fail,
+        }: PhysicalReadMemOps,
+    ) -> Result<()> {
+        let align = self.align;
+
+        // every aligned region is placed into a single scratch buffer
+        let mut regions = vec![];
+        let mut ops = vec![];
+        let mut scratch_len: umem = 0;
+
+        for CTup3(addr, meta_addr, data) in inp {
+            let start = addr.address().as_mem_aligned(align);
+            let end = addr.address().to_umem().saturating_add(data.len() as umem);
+            let end = end.saturating_add((align - end % align) % align);
+
+            // page information is only kept if the aligned region stays within the page
+            let region_addr = if addr.has_page() && align <= addr.page_size() {
+                PhysicalAddress::with_page(start, addr.page_type(), addr.page_size())
+            } else {
+                start.into()
+            };
+
+            let offset = scratch_len + addr.address().distance_to(start);
+            regions.push((region_addr, scratch_len, end - start.to_umem()));
+            ops.push((offset, CTup2(meta_addr, data)));
+            scratch_len += end - start.to_umem();
+        }
+
+        let mut scratch = vec![0u8; scratch_len as usize];
+        let mut failed = vec![];
+
+        {
+            let mut rest = scratch.as_mut_slice();
+            let mut bufs = Vec::with_capacity(regions.len());
+            for (addr, offset, len) in regions {
+                let (head, tail) = core::mem::take(&mut rest).split_at_mut(len as usize);
+                rest = tail;
+                // the meta address holds the offset within `scratch`
+                bufs.push(CTup3(addr, Address::from(offset), head.into()));
+            }
+
+            let callback = &mut |CTup2(meta, d): ReadData| {
+                failed.push((meta.to_umem(), d.len() as umem));
+                true
+            };
+
+            let mem = &mut self.mem;
+            MemOps::with_raw(bufs.into_iter(), None, Some(&mut callback.into()), |data| {
+                mem.phys_read_raw_iter(data)
+            })?;
+        }
+
+        failed.sort_unstable_by_key(|&(offset, _)| offset);
+
+        for (offset, CTup2(meta_addr, mut data)) in ops {
+            let end = offset + data.len() as umem;
+            data.copy_from_slice(&scratch[offset as usize..end as usize]);
+
+            // report the successful and failed parts of the requested range individually
+            let mut rest = Some(CTup2(meta_addr, data));
+            let mut pos = offset;
+
+            for &(fail_start, fail_len) in failed.iter() {
+                let fail_end = fail_start + fail_len;
+                if fail_end <= pos || fail_start >= end {
+                    continue;
+                }
+
+                if fail_start > pos {
+                    let (head, tail) = match rest.take() {
+                        Some(rest) => rest.split_at(fail_start - pos),
+                        None => break,
+                    };
+                    if let Some(head) = head {
+                        opt_call(out.as_deref_mut(), head);
+                    }
+                    rest = tail;
+                    pos = fail_start;
+                }
+
+                let fail_end = core::cmp::min(fail_end, end);
+                let (head, tail) = match rest.take() {
+                    Some(rest) => rest.split_at(fail_end - pos),
+                    None => break,
+                };
+                if let Some(head) = head {
+                    opt_call(out_fail.as_deref_mut(), head);
+                }
+                rest = tail;
+                pos = fail_end;
+            }
+
+            if let Some(rest) = rest {
+                opt_call(out.as_deref_mut(), rest);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn phys_write_raw_iter(&mut self, data: PhysicalWriteMemOps) -> Result<()> {
+        self.mem.phys_write_raw_iter(data)
+    }
+
+    #[inline]
+    fn metadata(&self) -> PhysicalMemoryMetadata {
+        self.mem.metadata()
+    }
+
+    #[inline]
+    fn set_mem_map(&mut self, mem_map: &[PhysicalMemoryMapping]) {
+        self.mem.set_mem_map(mem_map)
+    }
+
+    #[inline]
+    fn prefetch(&mut self, ranges: &[PhysicalReadData]) {
+        self.mem.prefetch(ranges)
+    }
+
+    #[inline]
+    fn phys_mem_ranges(&self, callback: PhysicalMemoryRangeCallback) {
+        self.mem.phys_mem_ranges(callback)
+    }
+}
+
+#[cfg(feature = "plugins")]
+::cglue::cglue_impl_group!(
+    AlignedPhysicalMemory<T: PhysicalMemory>,
+    crate::plugins::ConnectorInstance,
+    {}
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dummy::DummyMemory;
+    use crate::mem::MemoryView;
+    use crate::types::size;
+
+    #[test]
+    fn aligned_read_unaligned() {
+        let mut mem = DummyMemory::new(size::mb(1));
+        let data = (0..0x200).map(|i| i as u8).collect::<Vec<_>>();
+        mem.phys_write(0x1000.into(), data.as_slice()).unwrap();
+
+        let mut mem = AlignedPhysicalMemory::new(mem, 0x100);
+
+        let mut buf = vec![0u8; 0x123];
+        mem.phys_view()
+            .read_raw_into(0x1037.into(), &mut buf)
+            .unwrap();
+        assert_eq!(buf.as_slice(), &data[0x37..0x37 + 0x123]);
+
+        let mut a = [0u8; 4];
+        let mut b = [0u8; 4];
+        let mut view = mem.phys_view();
+        let mut batcher = view.batcher();
+        batcher.read_raw_into(0x1001.into(), &mut a);
+        batcher.read_raw_into(0x11fc.into(), &mut b);
+        batcher.commit_rw().unwrap();
+        drop(batcher);
+        assert_eq!(a, [1, 2, 3, 4]);
+        assert_eq!(b, [0xfc, 0xfd, 0xfe, 0xff]);
+    }
+}
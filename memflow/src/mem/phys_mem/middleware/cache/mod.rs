@@ -33,8 +33,8 @@ use crate::architecture::ArchitectureObj;
 use crate::error::{Error, ErrorKind, ErrorOrigin, Result};
 use crate::iter::PageChunks;
 use crate::mem::{
-    MemOps, PhysicalMemory, PhysicalMemoryMapping, PhysicalMemoryMetadata, PhysicalReadMemOps,
-    PhysicalWriteMemOps,
+    MemOps, PhysicalMemory, PhysicalMemoryMapping, PhysicalMemoryMetadata,
+    PhysicalMemoryRangeCallback, PhysicalReadData, PhysicalReadMemOps, PhysicalWriteMemOps,
 };
 use cglue::tuple::*;
 use page_cache::{PageCache, PageValidity};
@@ -175,6 +175,16 @@ impl<'a, T: PhysicalMemory, Q: CacheValidator> PhysicalMemory for CachedPhysical
     fn set_mem_map(&mut self, mem_map: &[PhysicalMemoryMapping]) {
         self.mem.set_mem_map(mem_map)
     }
+
+    #[inline]
+    fn prefetch(&mut self, ranges: &[PhysicalReadData]) {
+        self.mem.prefetch(ranges)
+    }
+
+    #[inline]
+    fn phys_mem_ranges(&self, callback: PhysicalMemoryRangeCallback) {
+        self.mem.phys_mem_ranges(callback)
+    }
 }
 
 /// The builder interface for constructing a `CachedPhysicalMemory` object.
@@ -0,0 +1,137 @@
+use crate::cglue::*;
+use crate::error::Result;
+use crate::iter::SplitAtIndex;
+use crate::mem::{
+    MemOps, PhysicalMemory, PhysicalMemoryMapping, PhysicalMemoryMetadata,
+    PhysicalMemoryRangeCallback, PhysicalReadData, PhysicalReadMemOps, PhysicalWriteMemOps,
+};
+use crate::types::{umem, Address, PhysicalAddress};
+
+/// The chunked middleware splits up operations exceeding a maximum transfer size.
+///
+/// Some connectors (e.g. FPGA based DMA devices) are limited in the amount of bytes
+/// they are able to transfer at once. This middleware splits every operation into chunks
+/// of at most `max_transfer` bytes. Every chunk is reported individually to the
+/// success and failure callbacks, thus partial results are preserved.
+///
+/// Since this middleware implements [`PhysicalMemory`] it can be used as a replacement
+/// in all structs and functions that require the [`PhysicalMemory`] trait.
+#[derive(Clone)]
+pub struct ChunkedPhysicalMemory<T> {
+    mem: T,
+    max_transfer: umem,
+}
+
+impl<T: PhysicalMemory> ChunkedPhysicalMemory<T> {
+    /// Constructs a new middleware with the given maximum transfer size.
+    ///
+    /// # Examples
+    /// ```
+    /// # const MAGIC_VALUE: u64 = 0x23bd_318f_f3a3_5821;
+    /// use memflow::types::size;
+    /// use memflow::mem::{PhysicalMemory, ChunkedPhysicalMemory, MemoryView};
+    ///
+    /// fn build<T: PhysicalMemory>(mem: T) -> T {
+    ///     let mut middleware = ChunkedPhysicalMemory::new(mem, size::kb(64));
+    ///
+    ///     // reads larger than 64kb are split up transparently
+    ///     let value: u64 = middleware.phys_view().read(0.into()).unwrap();
+    ///     assert_eq!(value, MAGIC_VALUE);
+    ///     let buf = middleware.phys_view().read_raw(0.into(), size::mb(1)).unwrap();
+    ///     assert_eq!(buf.len(), size::mb(1));
+    ///
+    ///     // retrieve ownership of mem and return it back
+    ///     middleware.into_inner()
+    /// }
+    /// # use memflow::dummy::DummyMemory;
+    /// # let mut mem = DummyMemory::new(size::mb(4));
+    /// # mem.phys_write(0.into(), &MAGIC_VALUE).unwrap();
+    /// # build(mem);
+    /// ```
+    pub fn new(mem: T, max_transfer: usize) -> Self {
+        Self {
+            mem,
+            max_transfer: core::cmp::max(max_transfer, 1) as umem,
+        }
+    }
+
+    /// Consumes self and returns the containing memory object.
+    pub fn into_inner(self) -> T {
+        self.mem
+    }
+}
+
+/// Splits a single operation into chunks of at most `max_transfer` bytes.
+fn split_chunks<T: SplitAtIndex>(
+    CTup3(addr, meta_addr, data): CTup3<PhysicalAddress, Address, T>,
+    max_transfer: umem,
+) -> impl Iterator<Item = CTup3<PhysicalAddress, Address, T>> {
+    let mut rest = Some(CTup3(addr.address(), meta_addr, data));
+
+    core::iter::from_fn(move || {
+        let (head, tail) = rest.take()?.split_at(max_transfer);
+        rest = tail;
+        head.map(|CTup3(chunk_addr, meta_addr, data)| {
+            let chunk_addr = if addr.has_page() {
+                PhysicalAddress::with_page(chunk_addr, addr.page_type(), addr.page_size())
+            } else {
+                chunk_addr.into()
+            };
+            CTup3(chunk_addr, meta_addr, data)
+        })
+    })
+}
+
+// forward PhysicalMemory trait fncs
+impl<T: PhysicalMemory> PhysicalMemory for ChunkedPhysicalMemory<T> {
+    #[inline]
+    fn phys_read_raw_iter(
+        &mut self,
+        MemOps { inp, out, out_fail }: PhysicalReadMemOps,
+    ) -> Result<()> {
+        let max_transfer = self.max_transfer;
+        let iter = inp.flat_map(move |data| split_chunks(data, max_transfer));
+
+        let mem = &mut self.mem;
+        MemOps::with_raw(iter, out, out_fail, |data| mem.phys_read_raw_iter(data))
+    }
+
+    #[inline]
+    fn phys_write_raw_iter(
+        &mut self,
+        MemOps { inp, out, out_fail }: PhysicalWriteMemOps,
+    ) -> Result<()> {
+        let max_transfer = self.max_transfer;
+        let iter = inp.flat_map(move |data| split_chunks(data, max_transfer));
+
+        let mem = &mut self.mem;
+        MemOps::with_raw(iter, out, out_fail, |data| mem.phys_write_raw_iter(data))
+    }
+
+    #[inline]
+    fn metadata(&self) -> PhysicalMemoryMetadata {
+        self.mem.metadata()
+    }
+
+    #[inline]
+    fn set_mem_map(&mut self, mem_map: &[PhysicalMemoryMapping]) {
+        self.mem.set_mem_map(mem_map)
+    }
+
+    #[inline]
+    fn prefetch(&mut self, ranges: &[PhysicalReadData]) {
+        self.mem.prefetch(ranges)
+    }
+
+    #[inline]
+    fn phys_mem_ranges(&self, callback: PhysicalMemoryRangeCallback) {
+        self.mem.phys_mem_ranges(callback)
+    }
+}
+
+#[cfg(feature = "plugins")]
+::cglue::cglue_impl_group!(
+    ChunkedPhysicalMemory<T: PhysicalMemory>,
+    crate::plugins::ConnectorInstance,
+    {}
+);
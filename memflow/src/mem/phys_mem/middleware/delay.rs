@@ -2,8 +2,8 @@ use ::std::{thread, time::Duration};
 
 use crate::error::Result;
 use crate::mem::{
-    PhysicalMemory, PhysicalMemoryMapping, PhysicalMemoryMetadata, PhysicalReadMemOps,
-    PhysicalWriteMemOps,
+    PhysicalMemory, PhysicalMemoryMapping, PhysicalMemoryMetadata, PhysicalMemoryRangeCallback,
+    PhysicalReadData, PhysicalReadMemOps, PhysicalWriteMemOps,
 };
 
 /// The delay middleware introduces delay and jitter into physical reads which allows
@@ -104,6 +104,16 @@ impl<T: PhysicalMemory> PhysicalMemory for DelayedPhysicalMemory<T> {
     fn set_mem_map(&mut self, mem_map: &[PhysicalMemoryMapping]) {
         self.mem.set_mem_map(mem_map)
     }
+
+    #[inline]
+    fn prefetch(&mut self, ranges: &[PhysicalReadData]) {
+        self.mem.prefetch(ranges)
+    }
+
+    #[inline]
+    fn phys_mem_ranges(&self, callback: PhysicalMemoryRangeCallback) {
+        self.mem.phys_mem_ranges(callback)
+    }
 }
 
 /// The builder interface for constructing a `DelayedPhysicalMemory` object.
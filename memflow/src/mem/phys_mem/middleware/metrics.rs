@@ -2,8 +2,8 @@ use ::log::info;
 use ::std::{collections::VecDeque, time::Instant};
 
 use crate::mem::{
-    PhysicalMemory, PhysicalMemoryMapping, PhysicalMemoryMetadata, PhysicalReadMemOps,
-    PhysicalWriteMemOps,
+    PhysicalMemory, PhysicalMemoryMapping, PhysicalMemoryMetadata, PhysicalMemoryRangeCallback,
+    PhysicalReadData, PhysicalReadMemOps, PhysicalWriteMemOps,
 };
 use crate::{error::Result, mem::MemOps};
 
@@ -154,6 +154,16 @@ impl<T: PhysicalMemory> PhysicalMemory for PhysicalMemoryMetrics<T> {
     fn set_mem_map(&mut self, mem_map: &[PhysicalMemoryMapping]) {
         self.mem.set_mem_map(mem_map)
     }
+
+    #[inline]
+    fn prefetch(&mut self, ranges: &[PhysicalReadData]) {
+        self.mem.prefetch(ranges)
+    }
+
+    #[inline]
+    fn phys_mem_ranges(&self, callback: PhysicalMemoryRangeCallback) {
+        self.mem.phys_mem_ranges(callback)
+    }
 }
 
 #[cfg(feature = "plugins")]
@@ -1,13 +1,21 @@
+pub mod aligned;
 pub mod cache;
+pub mod chunked;
 
 #[cfg(feature = "std")]
 pub mod delay;
 #[cfg(feature = "std")]
 pub mod metrics;
 
+#[doc(hidden)]
+pub use aligned::*;
+
 #[doc(hidden)]
 pub use cache::*;
 
+#[doc(hidden)]
+pub use chunked::*;
+
 #[cfg(feature = "std")]
 #[doc(hidden)]
 pub use delay::*;
@@ -159,6 +159,56 @@ pub trait PhysicalMemory: Send {
     #[inline]
     fn set_mem_map(&mut self, _mem_map: &[PhysicalMemoryMapping]) {}
 
+    /// Hints the physical memory object that the given ranges are about to be read
+    ///
+    /// Connectors with deep request queues (e.g. FPGA based DMA devices) can use this hint
+    /// to batch-request the pages ahead of the actual reads. The contents of the
+    /// buffers passed in are not touched and the ranges are not required to be read afterwards.
+    ///
+    /// By default this is a no-op.
+    #[inline]
+    fn prefetch(&mut self, _ranges: &[PhysicalReadData]) {}
+
+    /// Retrieves the physical memory ranges that are backed by this object
+    ///
+    /// Some connectors (e.g. coredumps) only back parts of the physical address space
+    /// and reading from the gaps will fail. Such connectors should report every backed
+    /// range as `(base, size)` pair, so that callers can avoid reading the gaps.
+    /// The iteration stops as soon as the callback returns `false`.
+    ///
+    /// By default a single range spanning up to `metadata().max_address` is reported.
+    fn phys_mem_ranges(&self, mut callback: PhysicalMemoryRangeCallback) {
+        let max_address = self.metadata().max_address;
+        callback.call(CTup2(
+            Address::null(),
+            max_address.to_umem().saturating_add(1),
+        ));
+    }
+
+    /// Retrieves a list of all backed physical memory ranges
+    ///
+    /// See [`phys_mem_ranges`](Self::phys_mem_ranges) for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::types::{size, umem, Address};
+    /// use memflow::mem::PhysicalMemory;
+    /// # let mem = memflow::dummy::DummyMemory::new(size::mb(16));
+    ///
+    /// let ranges = mem.phys_mem_ranges_vec();
+    ///
+    /// assert_eq!(ranges.len(), 1);
+    /// assert_eq!(ranges[0].0, Address::null());
+    /// assert_eq!(ranges[0].1, size::mb(16) as umem);
+    /// ```
+    #[skip_func]
+    fn phys_mem_ranges_vec(&self) -> Vec<PhysicalMemoryRange> {
+        let mut ret = vec![];
+        self.phys_mem_ranges((&mut ret).into());
+        ret
+    }
+
     #[skip_func]
     fn phys_read_into<T: Pod + ?Sized>(&mut self, addr: PhysicalAddress, out: &mut T) -> Result<()>
     where
@@ -1,6 +1,7 @@
 use std::prelude::v1::*;
 
-use crate::architecture::{ArchitectureObj, Endianess};
+use crate::architecture::{x86::X86VirtualTranslate, ArchitectureObj, Endianess};
+use crate::dataview::Pod;
 use crate::error::{Error, Result, *};
 use crate::mem::memory_view::*;
 use crate::mem::{
@@ -12,12 +13,40 @@ use crate::mem::{
     },
     MemoryView, PhysicalMemory, PhysicalMemoryMetadata,
 };
-use crate::types::{umem, Address, PhysicalAddress};
+use crate::types::{size, umem, Address, PhysicalAddress};
 use cglue::tuple::*;
 
+use core::mem::{size_of, MaybeUninit};
+
 use bumpalo::{collections::Vec as BumpVec, Bump};
 use cglue::callback::FromExtend;
 
+/// Minimum amount of translated bytes in a single read before [`PhysicalMemory::prefetch`] is invoked.
+const PREFETCH_THRESHOLD: usize = size::kb(64);
+
+/// Counters of the operations performed by a [`VirtualDma`] object.
+///
+/// Caching of physical memory happens below this object, use the
+/// [`PhysicalMemoryMetrics`](crate::mem::PhysicalMemoryMetrics) middleware to observe
+/// what actually reaches the connector.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct VirtualDmaStats {
+    /// Number of translated chunks (usually a single page each)
+    pub translations: u64,
+    /// Number of physical reads issued after merging contiguous chunks
+    pub phys_reads: u64,
+    /// Number of bytes requested from physical memory by reads
+    pub bytes_read: u64,
+    /// Number of physical writes issued
+    pub phys_writes: u64,
+    /// Number of bytes requested to be written to physical memory
+    pub bytes_written: u64,
+    /// Number of translations served by a caching translator
+    /// (e.g. [`CachedVirtualTranslate`](crate::mem::CachedVirtualTranslate))
+    pub cache_hits: u64,
+}
+
 /// The VirtualDma struct provides a default implementation to access virtual memory
 /// from user provided [`PhysicalMemory`] and [`VirtualTranslate2`] objects.
 ///
@@ -28,6 +57,7 @@ pub struct VirtualDma<T, V, D> {
     proc_arch: ArchitectureObj,
     translator: D,
     arena: Bump,
+    stats: VirtualDmaStats,
 }
 
 impl<T: PhysicalMemory, D: VirtualTranslate3> VirtualDma<T, DirectTranslate, D> {
@@ -73,6 +103,7 @@ impl<T: PhysicalMemory, D: VirtualTranslate3> VirtualDma<T, DirectTranslate, D>
             proc_arch: arch.into(),
             translator,
             arena: Bump::new(),
+            stats: VirtualDmaStats::default(),
         }
     }
 }
@@ -117,6 +148,7 @@ impl<T: PhysicalMemory, V: VirtualTranslate2, D: VirtualTranslate3> VirtualDma<T
             proc_arch: arch.into(),
             translator,
             arena: Bump::new(),
+            stats: VirtualDmaStats::default(),
         }
     }
 
@@ -178,6 +210,186 @@ impl<T: PhysicalMemory, V: VirtualTranslate2, D: VirtualTranslate3> VirtualDma<T
     pub fn vat(&mut self) -> &mut V {
         &mut self.vat
     }
+
+    /// Returns the operation counters accumulated since construction or the last reset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::types::size;
+    /// use memflow::architecture::x86::x64;
+    /// use memflow::mem::{MemoryView, VirtualDma};
+    /// use memflow::cglue::ForwardMut;
+    /// # use memflow::dummy::{DummyMemory, DummyOs};
+    ///
+    /// # let mem = DummyMemory::new(size::mb(4));
+    /// # let (mut os, dtb, virt_base) = DummyOs::new_and_dtb(mem, size::mb(2), &[]);
+    /// let mut virt_mem = VirtualDma::new(os.forward_mut(), x64::ARCH, x64::new_translator(dtb));
+    ///
+    /// let _: u64 = virt_mem.read(virt_base).unwrap();
+    /// assert_eq!(virt_mem.stats().phys_reads, 1);
+    /// assert_eq!(virt_mem.stats().bytes_read, 8);
+    ///
+    /// virt_mem.reset_stats();
+    /// assert_eq!(virt_mem.stats().bytes_read, 0);
+    /// ```
+    ///
+    /// When using a caching translator, translations served from its cache are counted as well:
+    ///
+    /// ```
+    /// use memflow::types::size;
+    /// use memflow::architecture::x86::x64;
+    /// use memflow::mem::{CachedVirtualTranslate, DirectTranslate, MemoryView, VirtualDma};
+    /// use memflow::cglue::ForwardMut;
+    /// # use memflow::dummy::{DummyMemory, DummyOs};
+    ///
+    /// # let mem = DummyMemory::new(size::mb(4));
+    /// # let (mut os, dtb, virt_base) = DummyOs::new_and_dtb(mem, size::mb(2), &[]);
+    /// let vat = CachedVirtualTranslate::builder(DirectTranslate::new())
+    ///     .arch(x64::ARCH)
+    ///     .build()
+    ///     .unwrap();
+    /// let mut virt_mem =
+    ///     VirtualDma::with_vat(os.forward_mut(), x64::ARCH, x64::new_translator(dtb), vat);
+    ///
+    /// let _: u64 = virt_mem.read(virt_base).unwrap();
+    /// let _: u64 = virt_mem.read(virt_base).unwrap();
+    /// assert_eq!(virt_mem.stats().translations, 2);
+    /// assert_eq!(virt_mem.stats().cache_hits, 1);
+    /// ```
+    pub fn stats(&self) -> VirtualDmaStats {
+        self.stats
+    }
+
+    /// Resets all operation counters to zero.
+    pub fn reset_stats(&mut self) {
+        self.stats = VirtualDmaStats::default();
+    }
+
+    /// Translates a single address and updates the operation counters.
+    fn virt_to_phys_counted(&mut self, addr: Address) -> Result<PhysicalAddress> {
+        let hits = self.vat.cache_hits();
+        let ret = self.virt_to_phys(addr);
+        self.stats.cache_hits += self.vat.cache_hits().saturating_sub(hits) as u64;
+        if ret.is_ok() {
+            self.stats.translations += 1;
+        }
+        ret
+    }
+
+    /// Reads a virtual memory region which is known to be physically contiguous.
+    ///
+    /// Only the start address is translated and the entire buffer is read with a single
+    /// physical read. The caller has to ensure that the region is backed by physically contiguous
+    /// memory (e.g. a large page), otherwise unrelated physical memory will be read.
+    pub fn virt_read_contiguous_hint(
+        &mut self,
+        addr: Address,
+        out: &mut [u8],
+    ) -> PartialResult<()> {
+        let translated = self.virt_to_phys_counted(addr)?;
+        // the read may span multiple pages, so the page size of the first page is not passed on
+        let mut paddr = PhysicalAddress::from(translated.address());
+        paddr.page_type = translated.page_type();
+
+        self.stats.phys_reads += 1;
+        self.stats.bytes_read += out.len() as u64;
+        self.phys_mem.phys_read_into(paddr, out)?;
+        Ok(())
+    }
+
+    /// Translates a single virtual address and reads the value directly from physical memory.
+    ///
+    /// This is a fast path for pointer chasing where a single small value is read per hop.
+    /// Values which cross a page boundary are read through the regular [`MemoryView`] path instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::types::{size, Address};
+    /// use memflow::architecture::x86::x64;
+    /// use memflow::mem::VirtualDma;
+    /// use memflow::cglue::ForwardMut;
+    /// # use memflow::dummy::{DummyMemory, DummyOs};
+    ///
+    /// # let mem = DummyMemory::new(size::mb(4));
+    /// # let (mut os, dtb, virt_base) = DummyOs::new_and_dtb(mem, size::mb(2), &[255, 0, 255, 0, 255, 0, 255, 0]);
+    /// let mut virt_mem = VirtualDma::new(os.forward_mut(), x64::ARCH, x64::new_translator(dtb));
+    ///
+    /// let value: u64 = virt_mem.translate_and_read(virt_base).unwrap();
+    /// assert_eq!(value, 0x00ff_00ff_00ff_00ff);
+    /// ```
+    pub fn translate_and_read<P: Pod>(&mut self, addr: Address) -> PartialResult<P> {
+        let paddr = self.virt_to_phys_counted(addr)?;
+
+        if !paddr.has_page()
+            || (paddr.address() - paddr.page_base()) as umem + size_of::<P>() as umem
+                > paddr.page_size()
+        {
+            return self.read(addr);
+        }
+
+        self.stats.phys_reads += 1;
+        self.stats.bytes_read += size_of::<P>() as u64;
+
+        let mut obj: P = unsafe { MaybeUninit::zeroed().assume_init() };
+        self.phys_mem.phys_read_into(paddr, &mut obj)?;
+        Ok(obj)
+    }
+
+    /// Reads from the address space described by `translator` instead of the bound one.
+    ///
+    /// The bound translator is restored after the read, this allows peeking into other
+    /// address spaces without having to construct a separate `VirtualDma` object.
+    pub fn read_raw_into_with_translator(
+        &mut self,
+        translator: D,
+        addr: Address,
+        out: &mut [u8],
+    ) -> PartialResult<()> {
+        let prev = self.set_translator(translator);
+        let ret = self.read_raw_into(addr, out);
+        self.set_translator(prev);
+        ret
+    }
+}
+
+impl<T: PhysicalMemory, V: VirtualTranslate2> VirtualDma<T, V, X86VirtualTranslate> {
+    /// Reads from the address space of the given directory table base instead of the bound one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::types::{size, Address};
+    /// use memflow::architecture::x86::x64;
+    /// use memflow::mem::{MemoryView, VirtualDma};
+    /// use memflow::cglue::ForwardMut;
+    /// # use memflow::dummy::{DummyMemory, DummyOs};
+    ///
+    /// # let mem = DummyMemory::new(size::mb(8));
+    /// # let mut os = DummyOs::new(mem);
+    /// # let (dtb1, virt_base1) = os.alloc_dtb(size::mb(2), &[1, 2, 3, 4]);
+    /// # let (dtb2, virt_base2) = os.alloc_dtb(size::mb(2), &[5, 6, 7, 8]);
+    /// let mut virt_mem = VirtualDma::new(os.forward_mut(), x64::ARCH, x64::new_translator(dtb1));
+    ///
+    /// let mut buf = [0u8; 4];
+    /// virt_mem.read_raw_into_with_dtb(dtb2, virt_base2, &mut buf).unwrap();
+    /// assert_eq!(buf, [5, 6, 7, 8]);
+    ///
+    /// // the bound translator is unaffected
+    /// assert_eq!(virt_mem.translator().dtb(), dtb1);
+    /// # virt_mem.read_raw_into(virt_base1, &mut buf).unwrap();
+    /// # assert_eq!(buf, [1, 2, 3, 4]);
+    /// ```
+    pub fn read_raw_into_with_dtb(
+        &mut self,
+        dtb: Address,
+        addr: Address,
+        out: &mut [u8],
+    ) -> PartialResult<()> {
+        let translator = self.translator.with_dtb(dtb);
+        self.read_raw_into_with_translator(translator, addr, out)
+    }
 }
 
 impl<T, V, D> Clone for VirtualDma<T, V, D>
@@ -193,6 +405,7 @@ where
             proc_arch: self.proc_arch,
             translator: self.translator.clone(),
             arena: Bump::new(),
+            stats: VirtualDmaStats::default(),
         }
     }
 }
@@ -213,6 +426,7 @@ impl<T: PhysicalMemory, V: VirtualTranslate2, D: VirtualTranslate3> MemoryView
 
         let mut translation = BumpVec::with_capacity_in(inp.size_hint().0, &self.arena);
         let phys_mem = &mut self.phys_mem;
+        let hits = self.vat.cache_hits();
 
         self.vat.virt_to_phys_iter(
             phys_mem,
@@ -225,7 +439,48 @@ impl<T: PhysicalMemory, V: VirtualTranslate2, D: VirtualTranslate3> MemoryView
                 .into(),
         );
 
-        MemOps::with_raw(translation.into_iter(), out, out_fail, |data| {
+        self.stats.translations += translation.len() as u64;
+        self.stats.cache_hits += self.vat.cache_hits().saturating_sub(hits) as u64;
+
+        // merge physically contiguous runs so connectors can service them with a single transfer
+        let mut merged: BumpVec<PhysicalReadData> =
+            BumpVec::with_capacity_in(translation.len(), &self.arena);
+        for CTup3(paddr, meta, buf) in translation {
+            if let Some(CTup3(prev_paddr, prev_meta, prev_buf)) = merged.last_mut() {
+                if prev_paddr.page_type() == paddr.page_type()
+                    && prev_paddr.address() + prev_buf.len() == paddr.address()
+                    && *prev_meta + prev_buf.len() == meta
+                    && prev_buf.as_ptr().wrapping_add(prev_buf.len()) == buf.as_ptr()
+                {
+                    let len = prev_buf.len() + buf.len();
+                    // SAFETY: both buffers are adjacent chunks of the same output buffer
+                    // which got split up during translation.
+                    *prev_buf =
+                        unsafe { core::slice::from_raw_parts_mut(prev_buf.as_mut_ptr(), len) }
+                            .into();
+                    // the merged chunk spans multiple pages, so only the page type (which is
+                    // equal for all merged pages) is kept and the page size gets cleared
+                    let page_type = prev_paddr.page_type();
+                    *prev_paddr = PhysicalAddress::from(prev_paddr.address());
+                    prev_paddr.page_type = page_type;
+                    continue;
+                }
+            }
+            merged.push(CTup3(paddr, meta, buf));
+        }
+
+        // hint the connector about large reads so it can request the pages ahead of time
+        let read_size = merged
+            .iter()
+            .fold(0, |acc, CTup3(_, _, buf)| acc + buf.len());
+        if read_size >= PREFETCH_THRESHOLD {
+            phys_mem.prefetch(&merged);
+        }
+
+        self.stats.phys_reads += merged.len() as u64;
+        self.stats.bytes_read += read_size as u64;
+
+        MemOps::with_raw(merged.into_iter(), out, out_fail, |data| {
             phys_mem.phys_read_raw_iter(data)
         })
     }
@@ -242,6 +497,7 @@ impl<T: PhysicalMemory, V: VirtualTranslate2, D: VirtualTranslate3> MemoryView
 
         let mut translation = BumpVec::with_capacity_in(inp.size_hint().0, &self.arena);
         let phys_mem = &mut self.phys_mem;
+        let hits = self.vat.cache_hits();
 
         self.vat.virt_to_phys_iter(
             phys_mem,
@@ -254,6 +510,13 @@ impl<T: PhysicalMemory, V: VirtualTranslate2, D: VirtualTranslate3> MemoryView
                 .into(),
         );
 
+        self.stats.translations += translation.len() as u64;
+        self.stats.cache_hits += self.vat.cache_hits().saturating_sub(hits) as u64;
+        self.stats.phys_writes += translation.len() as u64;
+        self.stats.bytes_written += translation
+            .iter()
+            .fold(0, |acc, CTup3(_, _, buf)| acc + buf.len() as u64);
+
         MemOps::with_raw(translation.into_iter(), out, out_fail, |data| {
             phys_mem.phys_write_raw_iter(data)
         })
@@ -235,6 +235,11 @@ impl<V: VirtualTranslate2, Q: CacheValidator> VirtualTranslate2 for CachedVirtua
         self.hitc += hitc;
         self.misc += misc;
     }
+
+    #[inline]
+    fn cache_hits(&self) -> umem {
+        self.hitc
+    }
 }
 
 pub struct CachedVirtualTranslateBuilder<V, Q> {
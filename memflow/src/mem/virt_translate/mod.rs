@@ -568,6 +568,54 @@ pub trait VirtualTranslate: Send {
         virt
     }
 
+    /// Retrieve all virtual addresses that map to a given physical address.
+    ///
+    /// Unlike [`phys_to_virt_vec`](Self::phys_to_virt_vec), the physical address is not required
+    /// to be the start of a translation range. Every virtual address whose translation contains
+    /// `phys` is returned, including the offset into the page.
+    ///
+    /// # Remarks:
+    ///
+    /// This function walks the entire page table hierarchy of the address space. It is very
+    /// expensive and should not be used in hot paths.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use memflow::prelude::v1::*;
+    /// # use memflow::dummy::DummyOs;
+    ///
+    /// fn who_maps(mem: &mut impl VirtualTranslate, addr: Address) {
+    ///     let paddr = mem.virt_to_phys(addr + 0x123_usize).unwrap();
+    ///     let vaddrs = mem.reverse_translate(paddr);
+    ///     assert_eq!(&vaddrs, &[addr + 0x123_usize]);
+    /// }
+    /// # let mut proc = DummyOs::quick_process(size::mb(2), &[]);
+    /// # let addr = proc.info().address;
+    /// # who_maps(&mut proc.mem, addr);
+    /// ```
+    #[skip_func]
+    fn reverse_translate(&mut self, phys: PhysicalAddress) -> Vec<Address> {
+        let phys = phys.address();
+        let mut virt = vec![];
+
+        let callback = &mut |VirtualTranslation {
+                                 in_virtual,
+                                 size,
+                                 out_physical,
+                             }| {
+            let base = out_physical.address();
+            if phys >= base && phys.distance_to(base) < size {
+                virt.push(in_virtual + phys.distance_to(base));
+            }
+            true
+        };
+
+        self.virt_translation_map(callback.into());
+
+        virt
+    }
+
     /// Retrieves all mapped virtual pages.
     ///
     /// The [`virt_page_map`](Self::virt_page_map) function is a convenience wrapper for calling
@@ -830,6 +878,14 @@ where
         );
         output.map(Ok).unwrap_or_else(|| Err(output_err.unwrap()))
     }
+
+    /// Returns the number of translations that were served from a cache
+    ///
+    /// Translators without a cache always return 0.
+    #[inline]
+    fn cache_hits(&self) -> umem {
+        0
+    }
 }
 
 // forward impls
@@ -854,6 +910,11 @@ where
     {
         (**self).virt_to_phys_iter(phys_mem, translator, addrs, out, out_fail)
     }
+
+    #[inline]
+    fn cache_hits(&self) -> umem {
+        (**self).cache_hits()
+    }
 }
 
 /// Translates virtual memory to physical using internal translation base (usually a process' dtb)
@@ -25,7 +25,7 @@ pub use keyboard::{Keyboard, KeyboardState, OsKeyboard};
 
 pub use module::{
     ExportCallback, ExportInfo, ImportCallback, ImportInfo, ModuleAddressCallback,
-    ModuleAddressInfo, ModuleInfo, ModuleInfoCallback, SectionCallback, SectionInfo,
+    ModuleAddressInfo, ModuleInfo, ModuleInfoCallback, ModulePeInfo, SectionCallback, SectionInfo,
 };
 
 pub use process::{Pid, Process, ProcessInfo, ProcessInfoCallback, ProcessState};
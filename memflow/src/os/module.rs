@@ -44,6 +44,44 @@ pub struct ModuleInfo {
     pub arch: ArchitectureIdent,
 }
 
+/// Directories containing operating system modules, lowercase and with `\` separators
+const SYSTEM_MODULE_DIRS: &[&str] = &[
+    "\\windows\\system32\\",
+    "\\windows\\syswow64\\",
+    "\\systemroot\\system32\\",
+];
+
+impl ModuleInfo {
+    /// Returns `true` if the module resides in one of the operating system directories.
+    ///
+    /// This is a path based heuristic. The path is matched case-insensitively against
+    /// the system directories, including the WoW64 redirection directory `SysWOW64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::architecture::ArchitectureIdent;
+    /// use memflow::os::ModuleInfo;
+    /// use memflow::types::Address;
+    ///
+    /// let module = ModuleInfo {
+    ///     address: Address::null(),
+    ///     parent_process: Address::null(),
+    ///     base: Address::null(),
+    ///     size: 0,
+    ///     name: "ntdll.dll".into(),
+    ///     path: "C:\\Windows\\SysWOW64\\ntdll.dll".into(),
+    ///     arch: ArchitectureIdent::X86(32, false),
+    /// };
+    ///
+    /// assert!(module.is_system_module());
+    /// ```
+    pub fn is_system_module(&self) -> bool {
+        let path = self.path.as_ref().to_lowercase().replace('/', "\\");
+        SYSTEM_MODULE_DIRS.iter().any(|dir| path.contains(dir))
+    }
+}
+
 pub type ModuleInfoCallback<'a> = OpaqueCallback<'a, ModuleInfo>;
 
 /// Pair of address and architecture used for callbacks
@@ -101,3 +139,20 @@ pub struct SectionInfo {
 }
 
 pub type SectionCallback<'a> = OpaqueCallback<'a, SectionInfo>;
+
+/// PE header information used to identify a loaded module
+///
+/// The `time_date_stamp` and `size_of_image` pair forms the key used by symbol servers
+/// to look up the matching debug information of a module.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "abi_stable", derive(::abi_stable::StableAbi))]
+pub struct ModulePeInfo {
+    /// `TimeDateStamp` field of the file header
+    pub time_date_stamp: u32,
+    /// `CheckSum` field of the optional header
+    pub checksum: u32,
+    /// `SizeOfImage` field of the optional header
+    pub size_of_image: u32,
+}
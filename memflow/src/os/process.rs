@@ -165,6 +165,103 @@ pub trait Process: Send {
         self.module_list_arch(None)
     }
 
+    /// Retrieves a module list for the process sorted by the module base address
+    ///
+    /// The sorted list can be binary searched when looking up the module containing an address.
+    #[skip_func]
+    fn module_list_sorted(&mut self) -> Result<Vec<ModuleInfo>> {
+        let mut ret = self.module_list()?;
+        ret.sort_by_key(|m| m.base);
+        Ok(ret)
+    }
+
+    /// Retrieves a list of up to `max` modules for the process
+    ///
+    /// The module walk is stopped as soon as `max` modules have been found.
+    ///
+    /// # Arguments
+    /// * `target_arch` - sets which architecture to retrieve the modules for (if emulated). Choose
+    /// between `Some(ProcessInfo::sys_arch())`, and `Some(ProcessInfo::proc_arch())`. `None` for all.
+    /// * `max` - maximum number of modules to retrieve
+    #[skip_func]
+    fn module_list_limited(
+        &mut self,
+        target_arch: Option<&ArchitectureIdent>,
+        max: usize,
+    ) -> Result<Vec<ModuleInfo>> {
+        let mut ret = vec![];
+        if max == 0 {
+            return Ok(ret);
+        }
+        let callback = &mut |info: ModuleInfo| {
+            ret.push(info);
+            ret.len() < max
+        };
+        self.module_list_callback(target_arch, callback.into())?;
+        Ok(ret)
+    }
+
+    /// Retrieves a list of module structure addresses for the process
+    ///
+    /// This is considerably cheaper than [`module_list_arch`](Self::module_list_arch) because
+    /// no module information (like names and paths) is being read. The returned addresses
+    /// can be passed to [`module_by_address`](Self::module_by_address) individually.
+    ///
+    /// Note that these are the addresses of the OS specific module structures and not the
+    /// image base addresses of the modules. Use [`ModuleInfo::base`] for the latter.
+    ///
+    /// # Arguments
+    /// * `target_arch` - sets which architecture to retrieve the modules for (if emulated). Choose
+    /// between `Some(ProcessInfo::sys_arch())`, and `Some(ProcessInfo::proc_arch())`. `None` for all.
+    #[skip_func]
+    fn module_address_list(
+        &mut self,
+        target_arch: Option<&ArchitectureIdent>,
+    ) -> Result<Vec<ModuleAddressInfo>> {
+        let mut ret = vec![];
+        self.module_address_list_callback(target_arch, (&mut ret).into())?;
+        Ok(ret)
+    }
+
+    /// Retrieves a list of all modules for which the given predicate returns `true`
+    ///
+    /// The predicate is evaluated on the [`ModuleAddressInfo`] of every module before any module
+    /// information is read, so only matching modules are passed to
+    /// [`module_by_address`](Self::module_by_address). The predicate therefore only sees the
+    /// address of the OS specific module structure, the image base is not known at this point.
+    ///
+    /// # Arguments
+    /// * `target_arch` - sets which architecture to retrieve the modules for (if emulated). Choose
+    /// between `Some(ProcessInfo::sys_arch())`, and `Some(ProcessInfo::proc_arch())`. `None` for all.
+    /// * `pred` - predicate deciding whether a module is part of the resulting list
+    #[skip_func]
+    fn module_list_filtered_by_address_info<F: FnMut(&ModuleAddressInfo) -> bool>(
+        &mut self,
+        target_arch: Option<&ArchitectureIdent>,
+        mut pred: F,
+    ) -> Result<Vec<ModuleInfo>>
+    where
+        Self: Sized,
+    {
+        let mut ret = vec![];
+        // This is safe, because control will flow back to the callback.
+        let sptr = self as *mut Self;
+        let callback = &mut |info: ModuleAddressInfo| {
+            if pred(&info) {
+                match unsafe { &mut *sptr }.module_by_address(info.address, info.arch) {
+                    Ok(info) => ret.push(info),
+                    Err(e) => {
+                        log::trace!("Error when reading module {:x} {:?}", info.address, e);
+                    }
+                }
+            }
+            true
+        };
+        unsafe { sptr.as_mut().unwrap() }
+            .module_address_list_callback(target_arch, callback.into())?;
+        Ok(ret)
+    }
+
     /// Retrieves address of the primary module structure of the process
     ///
     /// This will generally be for the initial executable that was run
@@ -186,6 +283,9 @@ pub trait Process: Send {
     ) -> Result<()>;
 
     /// Retrieves a list of all exports of a given module
+    ///
+    /// The iteration stops as soon as the callback returns `false`, this allows searching
+    /// for a specific export without collecting the entire export table.
     fn module_export_list_callback(
         &mut self,
         info: &ModuleInfo,
@@ -240,7 +340,7 @@ pub trait Process: Send {
 
     /// Finds a single export of a given module by its name
     fn module_export_by_name(&mut self, info: &ModuleInfo, name: &str) -> Result<ExportInfo> {
-        let mut ret = Err(Error(ErrorOrigin::OsLayer, ErrorKind::ImportNotFound));
+        let mut ret = Err(Error(ErrorOrigin::OsLayer, ErrorKind::ExportNotFound));
         let callback = &mut |data: ExportInfo| {
             if data.name.as_ref() == name {
                 ret = Ok(data);
@@ -253,9 +353,56 @@ pub trait Process: Send {
         ret
     }
 
+    /// Finds the closest export preceding the given address
+    ///
+    /// This looks up the module containing `addr` and returns it along with the export with the
+    /// highest address not exceeding `addr`, and the offset of `addr` from said export.
+    /// This is useful for labeling code addresses as `module!export+offset`.
+    ///
+    /// `None` is returned if no module contains the address, or if the containing module
+    /// has no exports preceding it.
+    #[skip_func]
+    fn nearest_export(&mut self, addr: Address) -> Result<Option<(ModuleInfo, ExportInfo, umem)>> {
+        let mut module = None;
+        let callback = &mut |info: ModuleInfo| {
+            if addr >= info.base && addr < info.base + info.size {
+                module = Some(info);
+                false
+            } else {
+                true
+            }
+        };
+        self.module_list_callback(None, callback.into())?;
+
+        let module = match module {
+            Some(module) => module,
+            None => return Ok(None),
+        };
+
+        let offset = (addr - module.base) as umem;
+        let mut nearest: Option<ExportInfo> = None;
+        let callback = &mut |data: ExportInfo| {
+            if data.offset <= offset
+                && nearest
+                    .as_ref()
+                    .map(|n| n.offset < data.offset)
+                    .unwrap_or(true)
+            {
+                nearest = Some(data);
+            }
+            true
+        };
+        self.module_export_list_callback(&module, callback.into())?;
+
+        Ok(nearest.map(|export| {
+            let delta = offset - export.offset;
+            (module, export, delta)
+        }))
+    }
+
     /// Finds a single section of a given module by its name
     fn module_section_by_name(&mut self, info: &ModuleInfo, name: &str) -> Result<SectionInfo> {
-        let mut ret = Err(Error(ErrorOrigin::OsLayer, ErrorKind::ImportNotFound));
+        let mut ret = Err(Error(ErrorOrigin::OsLayer, ErrorKind::SectionNotFound));
         let callback = &mut |data: SectionInfo| {
             if data.name.as_ref() == name {
                 ret = Ok(data);
@@ -282,6 +282,9 @@ pub trait Os: Send {
     ) -> Result<()>;
 
     /// Retrieves a list of all exports of a given module
+    ///
+    /// The iteration stops as soon as the callback returns `false`, this allows searching
+    /// for a specific export without collecting the entire export table.
     fn module_export_list_callback(
         &mut self,
         info: &ModuleInfo,
@@ -336,7 +339,7 @@ pub trait Os: Send {
 
     /// Finds a single export of a given module by its name
     fn module_export_by_name(&mut self, info: &ModuleInfo, name: &str) -> Result<ExportInfo> {
-        let mut ret = Err(Error(ErrorOrigin::OsLayer, ErrorKind::ImportNotFound));
+        let mut ret = Err(Error(ErrorOrigin::OsLayer, ErrorKind::ExportNotFound));
         let callback = &mut |data: ExportInfo| {
             if data.name.as_ref() == name {
                 ret = Ok(data);
@@ -351,7 +354,7 @@ pub trait Os: Send {
 
     /// Finds a single section of a given module by its name
     fn module_section_by_name(&mut self, info: &ModuleInfo, name: &str) -> Result<SectionInfo> {
-        let mut ret = Err(Error(ErrorOrigin::OsLayer, ErrorKind::ImportNotFound));
+        let mut ret = Err(Error(ErrorOrigin::OsLayer, ErrorKind::SectionNotFound));
         let callback = &mut |data: SectionInfo| {
             if data.name.as_ref() == name {
                 ret = Ok(data);
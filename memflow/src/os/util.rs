@@ -1,12 +1,13 @@
 //! Helpers for implementing several OS functions.
 
+use crate::architecture::ArchitectureIdent;
 use crate::error::*;
 use crate::mem::MemoryView;
 use crate::os::*;
-use crate::types::umem;
+use crate::types::{imem, umem, AddressRange};
 use cglue::prelude::v1::ReprCString;
-use dataview::PodMethods;
-use std::vec::Vec;
+use dataview::{Pod, PodMethods};
+use std::prelude::v1::*;
 
 #[cfg(feature = "goblin")]
 use goblin::{
@@ -117,6 +118,297 @@ fn custom_parse(buf: &[u8]) -> Result<Object<'_>> {
         .map_err(|_| Error(ErrorOrigin::OsLayer, ErrorKind::InvalidExeFile))
 }
 
+const IMAGE_DOS_SIGNATURE: u16 = 0x5a4d;
+const IMAGE_NT_SIGNATURE: u32 = 0x4550;
+const IMAGE_NT_OPTIONAL_HDR32_MAGIC: u16 = 0x10b;
+const IMAGE_NT_OPTIONAL_HDR64_MAGIC: u16 = 0x20b;
+
+/// Size of the signature, file header and a PE32+ optional header with all 16 data directories
+const IMAGE_NT_HEADERS_MAX_SIZE: usize = 0x108;
+const IMAGE_NUMBEROF_DIRECTORY_ENTRIES: usize = 16;
+
+/// Data directory entry of a PE image
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct ImageDataDirectory {
+    pub virtual_address: u32,
+    pub size: u32,
+}
+
+/// Parsed and validated PE headers of an image mapped in memory
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct ImageNtHeaders {
+    /// Offset of the NT headers from the image base (`e_lfanew`)
+    pub nt_headers_offset: u32,
+    pub machine: u16,
+    pub number_of_sections: u16,
+    pub time_date_stamp: u32,
+    pub size_of_optional_header: u16,
+    pub characteristics: u16,
+    /// `true` if the optional header is of the PE32+ format
+    pub is_64bit: bool,
+    pub address_of_entry_point: u32,
+    pub image_base: u64,
+    pub section_alignment: u32,
+    pub file_alignment: u32,
+    pub size_of_image: u32,
+    pub size_of_headers: u32,
+    pub checksum: u32,
+    pub subsystem: u16,
+    pub dll_characteristics: u16,
+    pub data_directories: Vec<ImageDataDirectory>,
+}
+
+#[inline]
+fn invalid_exe() -> Error {
+    Error(ErrorOrigin::OsLayer, ErrorKind::InvalidExeFile)
+}
+
+#[inline]
+pub fn module_image_nt_headers(
+    mem: &mut impl MemoryView,
+    info: &ModuleInfo,
+) -> Result<ImageNtHeaders> {
+    let headers = image_nt_headers(mem, info.base)?;
+
+    let bits = match info.arch {
+        ArchitectureIdent::X86(bits, _) => Some(bits),
+        ArchitectureIdent::AArch64(_) => Some(64),
+        _ => None,
+    };
+
+    match bits {
+        Some(64) if !headers.is_64bit => Err(invalid_exe()),
+        Some(32) if headers.is_64bit => Err(invalid_exe()),
+        _ => Ok(headers),
+    }
+}
+
+/// Findings of validating a mapped image against its module information
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct ModuleImageValidation {
+    /// The image starts with valid DOS and NT headers
+    pub has_pe_header: bool,
+    /// `SizeOfImage` matches the size of the module
+    pub size_matches: bool,
+    /// `AddressOfEntryPoint` lies within the module, or is not set
+    pub entrypoint_in_range: bool,
+}
+
+/// Checks whether the image mapped at the module base is consistent with the module information.
+///
+/// A mismatch, or a missing PE header, is a common indicator of a hollowed or replaced module.
+/// Failing to parse the headers is reported through [`ModuleImageValidation::has_pe_header`]
+/// rather than an error.
+pub fn validate_module_image(
+    mem: &mut impl MemoryView,
+    info: &ModuleInfo,
+) -> Result<ModuleImageValidation> {
+    let headers = match image_nt_headers(mem, info.base) {
+        Ok(headers) => headers,
+        Err(Error(_, ErrorKind::InvalidExeFile)) => return Ok(ModuleImageValidation::default()),
+        Err(e) => return Err(e),
+    };
+
+    let entry_point = headers.address_of_entry_point as umem;
+
+    Ok(ModuleImageValidation {
+        has_pe_header: true,
+        size_matches: headers.size_of_image as umem == info.size,
+        entrypoint_in_range: entry_point == 0 || entry_point < info.size,
+    })
+}
+
+/// Returns the absolute address of the entry point of a module.
+///
+/// This reads `AddressOfEntryPoint` from the optional header and rebases it onto the module base.
+/// Images without an entry point (e.g. resource-only DLLs) result in a `NotFound` error.
+pub fn module_entry_point(mem: &mut impl MemoryView, info: &ModuleInfo) -> Result<Address> {
+    let headers = module_image_nt_headers(mem, info)?;
+
+    if headers.address_of_entry_point == 0 {
+        return Err(Error(ErrorOrigin::OsLayer, ErrorKind::NotFound));
+    }
+
+    Ok(info.base + headers.address_of_entry_point as umem)
+}
+
+/// Returns the absolute address of the entry point of the primary module of a process.
+///
+/// See [`module_entry_point`] for more information.
+pub fn primary_module_entry_point(proc: &mut (impl Process + MemoryView)) -> Result<Address> {
+    let info = proc.primary_module()?;
+    module_entry_point(proc, &info)
+}
+
+/// Reads and validates the PE headers of the image mapped at `base`.
+///
+/// This reads the DOS header, follows `e_lfanew` and parses the file and optional headers.
+/// The optional header format (PE32 or PE32+) is chosen based on its magic value.
+/// Only the headers are read, so this is considerably cheaper than parsing the full image.
+pub fn image_nt_headers(mem: &mut impl MemoryView, base: Address) -> Result<ImageNtHeaders> {
+    let dos_magic: u16 = mem.read(base).data_part()?;
+    if dos_magic != IMAGE_DOS_SIGNATURE {
+        return Err(invalid_exe());
+    }
+    let nt_headers_offset: u32 = mem.read(base + 0x3cusize).data_part()?;
+
+    let mut buf = [0u8; IMAGE_NT_HEADERS_MAX_SIZE];
+    mem.read_raw_into(base + nt_headers_offset as umem, &mut buf)
+        .data_part()?;
+
+    let u16_at = |off: usize| u16::from_le_bytes([buf[off], buf[off + 1]]);
+    let u32_at =
+        |off: usize| u32::from_le_bytes([buf[off], buf[off + 1], buf[off + 2], buf[off + 3]]);
+    let u64_at = |off: usize| u32_at(off) as u64 | (u32_at(off + 4) as u64) << 32;
+
+    if u32_at(0) != IMAGE_NT_SIGNATURE {
+        return Err(invalid_exe());
+    }
+
+    // offsets of the file and optional headers relative to the NT headers
+    const FH: usize = 0x4;
+    const OH: usize = 0x18;
+
+    let (is_64bit, image_base, dirs_count_offset) = match u16_at(OH) {
+        IMAGE_NT_OPTIONAL_HDR32_MAGIC => (false, u32_at(OH + 0x1c) as u64, OH + 0x5c),
+        IMAGE_NT_OPTIONAL_HDR64_MAGIC => (true, u64_at(OH + 0x18), OH + 0x6c),
+        _ => return Err(invalid_exe()),
+    };
+
+    let dirs_count = std::cmp::min(
+        u32_at(dirs_count_offset) as usize,
+        IMAGE_NUMBEROF_DIRECTORY_ENTRIES,
+    );
+    let data_directories = (0..dirs_count)
+        .map(|i| dirs_count_offset + 4 + i * 8)
+        .map(|off| ImageDataDirectory {
+            virtual_address: u32_at(off),
+            size: u32_at(off + 4),
+        })
+        .collect();
+
+    Ok(ImageNtHeaders {
+        nt_headers_offset,
+        machine: u16_at(FH),
+        number_of_sections: u16_at(FH + 0x2),
+        time_date_stamp: u32_at(FH + 0x4),
+        size_of_optional_header: u16_at(FH + 0x10),
+        characteristics: u16_at(FH + 0x12),
+        is_64bit,
+        address_of_entry_point: u32_at(OH + 0x10),
+        image_base,
+        section_alignment: u32_at(OH + 0x20),
+        file_alignment: u32_at(OH + 0x24),
+        size_of_image: u32_at(OH + 0x38),
+        size_of_headers: u32_at(OH + 0x3c),
+        checksum: u32_at(OH + 0x40),
+        subsystem: u16_at(OH + 0x44),
+        dll_characteristics: u16_at(OH + 0x46),
+        data_directories,
+    })
+}
+
+/// Reads the identifying PE header fields of the image of a module.
+///
+/// The headers are validated against the architecture of the module,
+/// see [`module_image_nt_headers`] for more information.
+#[inline]
+pub fn module_pe_info(mem: &mut impl MemoryView, info: &ModuleInfo) -> Result<ModulePeInfo> {
+    module_image_nt_headers(mem, info).map(|headers| pe_info_from_headers(&headers))
+}
+
+/// Reads the identifying PE header fields of the image mapped at `base`.
+///
+/// See [`image_nt_headers`] for more information.
+pub fn pe_info(mem: &mut impl MemoryView, base: Address) -> Result<ModulePeInfo> {
+    image_nt_headers(mem, base).map(|headers| pe_info_from_headers(&headers))
+}
+
+#[inline]
+fn pe_info_from_headers(headers: &ImageNtHeaders) -> ModulePeInfo {
+    ModulePeInfo {
+        time_date_stamp: headers.time_date_stamp,
+        checksum: headers.checksum,
+        size_of_image: headers.size_of_image,
+    }
+}
+
+#[inline]
+fn read_image_ptr(mem: &mut impl MemoryView, addr: Address, is_64bit: bool) -> Result<u64> {
+    if is_64bit {
+        mem.read::<u64>(addr).data_part()
+    } else {
+        mem.read::<u32>(addr).data_part().map(|v| v as u64)
+    }
+}
+
+const IMAGE_DIRECTORY_ENTRY_TLS: usize = 9;
+
+/// Upper bound of TLS callbacks read, in case the array is not terminated
+const MAX_TLS_CALLBACKS: usize = 0x100;
+
+/// Reads the TLS callbacks of the image of a module.
+///
+/// Unlike [`tls_callbacks`] this rejects images whose PE format does not match the module
+/// architecture, as the pointer width of the TLS directory depends on it.
+#[inline]
+pub fn module_tls_callbacks(mem: &mut impl MemoryView, info: &ModuleInfo) -> Result<Vec<Address>> {
+    let headers = module_image_nt_headers(mem, info)?;
+    tls_callbacks_from_headers(mem, info.base, &headers)
+}
+
+/// Reads the TLS callbacks of the image mapped at `base`.
+///
+/// This follows `AddressOfCallBacks` of the TLS data directory and reads
+/// the null-terminated callback array. The addresses are rebased onto `base`.
+///
+/// An empty list is returned if the image has no TLS directory.
+pub fn tls_callbacks(mem: &mut impl MemoryView, base: Address) -> Result<Vec<Address>> {
+    let headers = image_nt_headers(mem, base)?;
+    tls_callbacks_from_headers(mem, base, &headers)
+}
+
+fn tls_callbacks_from_headers(
+    mem: &mut impl MemoryView,
+    base: Address,
+    headers: &ImageNtHeaders,
+) -> Result<Vec<Address>> {
+    let dir = match headers.data_directories.get(IMAGE_DIRECTORY_ENTRY_TLS) {
+        Some(dir) if dir.virtual_address != 0 && dir.size != 0 => *dir,
+        _ => return Ok(vec![]),
+    };
+
+    let rebase = |va: u64| base + va.wrapping_sub(headers.image_base) as umem;
+
+    let tls_dir = base + dir.virtual_address as umem;
+    let callbacks_offset = if headers.is_64bit {
+        0x18usize
+    } else {
+        0xcusize
+    };
+    let callbacks_va = read_image_ptr(mem, tls_dir + callbacks_offset, headers.is_64bit)?;
+
+    if callbacks_va == 0 {
+        return Ok(vec![]);
+    }
+
+    let ptr_size = if headers.is_64bit { 8 } else { 4 };
+    let callbacks = rebase(callbacks_va);
+
+    let mut ret = vec![];
+    for i in 0..MAX_TLS_CALLBACKS {
+        match read_image_ptr(mem, callbacks + i * ptr_size, headers.is_64bit)? {
+            0 => break,
+            callback => ret.push(rebase(callback)),
+        }
+    }
+
+    Ok(ret)
+}
+
 #[inline]
 pub fn module_import_list_callback(
     mem: &mut impl MemoryView,
@@ -232,12 +524,192 @@ pub fn module_export_list_callback(
     export_list_callback(mem, info.base, info.size, callback)
 }
 
+/// Finds a single export of a module by its name.
+///
+/// See [`export_by_name`] for more information.
+#[inline]
+pub fn module_export_by_name(
+    mem: &mut impl MemoryView,
+    info: &ModuleInfo,
+    name: &str,
+) -> Result<ExportInfo> {
+    export_by_name(mem, info.base, info.size, name)
+}
+
+/// Finds a single export of the PE image mapped at `base` by its name.
+///
+/// The export name table of PE images is sorted, which allows a binary search over it.
+/// Only the export directory tables and a logarithmic amount of names are read,
+/// instead of the full image. Forwarded exports are not resolved and result in an
+/// `ExportNotFound` error, just like missing ones.
+pub fn export_by_name(
+    mem: &mut impl MemoryView,
+    base: Address,
+    size: umem,
+    name: &str,
+) -> Result<ExportInfo> {
+    let not_found = || Error(ErrorOrigin::OsLayer, ErrorKind::ExportNotFound);
+
+    let tables = ExportTables::read(mem, base, size)?.ok_or_else(not_found)?;
+
+    let (mut low, mut high) = (0, tables.names.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match tables.name(mem, base, mid)?.as_str().cmp(name) {
+            std::cmp::Ordering::Less => low = mid + 1,
+            std::cmp::Ordering::Greater => high = mid,
+            std::cmp::Ordering::Equal => {
+                return tables
+                    .offset(mid)
+                    .map(|offset| ExportInfo {
+                        name: name.into(),
+                        offset,
+                    })
+                    .ok_or_else(not_found)
+            }
+        }
+    }
+
+    Err(not_found())
+}
+
+const IMAGE_DIRECTORY_ENTRY_EXPORT: usize = 0;
+const IMAGE_EXPORT_DIRECTORY_SIZE: usize = 0x28;
+
+/// Upper bound of the length of a single export name
+const MAX_EXPORT_NAME_LEN: usize = 0x1000;
+
+/// Name, ordinal and function tables of the export directory of a PE image
+struct ExportTables {
+    dir: ImageDataDirectory,
+    names: Vec<u32>,
+    ordinals: Vec<u16>,
+    functions: Vec<u32>,
+}
+
+impl ExportTables {
+    /// Reads the export directory tables of the image mapped at `base`.
+    ///
+    /// Returns `None` if the image has no export directory.
+    fn read(mem: &mut impl MemoryView, base: Address, size: umem) -> Result<Option<Self>> {
+        let headers = image_nt_headers(mem, base)?;
+
+        let dir = match headers.data_directories.get(IMAGE_DIRECTORY_ENTRY_EXPORT) {
+            Some(dir) if dir.virtual_address != 0 && dir.size != 0 => *dir,
+            _ => return Ok(None),
+        };
+
+        let mut buf = [0u8; IMAGE_EXPORT_DIRECTORY_SIZE];
+        mem.read_raw_into(base + dir.virtual_address as umem, &mut buf)
+            .data_part()?;
+
+        let u32_at =
+            |off: usize| u32::from_le_bytes([buf[off], buf[off + 1], buf[off + 2], buf[off + 3]]);
+
+        let number_of_functions = u32_at(0x14) as usize;
+        let number_of_names = u32_at(0x18) as usize;
+
+        // the tables have to lie within the image, this also bounds the allocations below
+        let table = |rva: u32, len: usize, elem_size: usize| {
+            if rva as umem + len as umem * elem_size as umem > size {
+                Err(invalid_exe())
+            } else {
+                Ok(base + rva as umem)
+            }
+        };
+
+        let functions_addr = table(u32_at(0x1c), number_of_functions, 4)?;
+        let names_addr = table(u32_at(0x20), number_of_names, 4)?;
+        let ordinals_addr = table(u32_at(0x24), number_of_names, 2)?;
+
+        let mut functions = vec![0u32; number_of_functions];
+        mem.read_into(functions_addr, &mut functions[..])
+            .data_part()?;
+        let mut names = vec![0u32; number_of_names];
+        mem.read_into(names_addr, &mut names[..]).data_part()?;
+        let mut ordinals = vec![0u16; number_of_names];
+        mem.read_into(ordinals_addr, &mut ordinals[..])
+            .data_part()?;
+
+        Ok(Some(Self {
+            dir,
+            names,
+            ordinals,
+            functions,
+        }))
+    }
+
+    /// Reads the `i`-th name of the export name table.
+    fn name(&self, mem: &mut impl MemoryView, base: Address, i: usize) -> Result<String> {
+        mem.read_char_string_n(base + self.names[i] as umem, MAX_EXPORT_NAME_LEN)
+            .data_part()
+    }
+
+    /// Returns the offset of the `i`-th named export.
+    ///
+    /// Returns `None` for forwarded exports, as they point into the export directory
+    /// instead of the code of the module.
+    fn offset(&self, i: usize) -> Option<umem> {
+        let rva = *self.functions.get(self.ordinals[i] as usize)?;
+
+        let dir_start = self.dir.virtual_address;
+        let dir_end = dir_start.saturating_add(self.dir.size);
+        if rva >= dir_start && rva < dir_end {
+            None
+        } else {
+            Some(rva as umem)
+        }
+    }
+
+    /// Calls `callback` for every named export until it returns `false`.
+    ///
+    /// Names are only read once the export is about to be passed to the callback.
+    fn call(&self, mem: &mut impl MemoryView, base: Address, callback: &mut ExportCallback) {
+        for i in 0..self.names.len() {
+            let offset = match self.offset(i) {
+                Some(offset) => offset,
+                None => continue,
+            };
+
+            let name = match self.name(mem, base, i) {
+                Ok(name) => name,
+                Err(e) => {
+                    log::trace!("Error when reading export name {} {:?}", i, e);
+                    continue;
+                }
+            };
+
+            if !callback.call(ExportInfo {
+                name: name.as_str().into(),
+                offset,
+            }) {
+                break;
+            }
+        }
+    }
+}
+
+/// Walks the exports of the image mapped at `base`.
+///
+/// For PE images the export directory is walked directly, names are read one by one and the
+/// walk stops as soon as the callback returns `false`. This avoids reading the full image when
+/// searching for a single export. Other images are read in full and parsed.
 pub fn export_list_callback(
     mem: &mut impl MemoryView,
     base: Address,
     size: umem,
     mut callback: ExportCallback,
 ) -> Result<()> {
+    match ExportTables::read(mem, base, size) {
+        Ok(Some(tables)) => {
+            tables.call(mem, base, &mut callback);
+            return Ok(());
+        }
+        Ok(None) => return Ok(()),
+        // fall back to parsing the full image
+        Err(e) => log::trace!("Unable to walk the PE export directory {:?}", e),
+    }
+
     let mut module_image = aligned_alloc(size as usize);
     let module_image = module_image.as_bytes_mut();
 
@@ -329,6 +801,588 @@ pub fn export_list_callback(
     ret
 }
 
+/// Reads a value located at a signed offset from an export.
+///
+/// This resolves the export `name` of the module `info`, and reads `T` at
+/// `export + offset`. The offset may be negative, since the value of interest
+/// frequently precedes the export.
+pub fn read_near_export<T: Pod + Sized>(
+    proc: &mut (impl Process + MemoryView),
+    info: &ModuleInfo,
+    name: &str,
+    offset: imem,
+) -> PartialResult<T> {
+    let export = proc.module_export_by_name(info, name)?;
+    proc.read(info.base + export.offset + offset)
+}
+
+/// Resolves the target of a branch instruction at `addr`.
+///
+/// Relative jumps and calls (`E8`, `E9` and `EB`) are resolved to their absolute target.
+/// Indirect jumps (`FF 25`) are followed by reading the pointer they jump through. The pointer
+/// is RIP-relative and 64 bits wide if `MemoryViewMetadata::arch_bits` is 64, otherwise the
+/// pointer address is absolute and 32 bits wide.
+///
+/// Any other instruction results in a `NotSupported` error.
+///
+/// # Examples
+///
+/// ```
+/// use memflow::os::util::resolve_rel_branch;
+/// # use memflow::dummy::DummyOs;
+/// # use memflow::os::Process;
+/// # use memflow::types::{size, Address};
+///
+/// let mut code = vec![0u8; 0x40];
+/// // jmp +0x10
+/// code[..5].copy_from_slice(&[0xe9, 0x10, 0x00, 0x00, 0x00]);
+/// // jmp [rip+0x0]
+/// code[0x20..0x26].copy_from_slice(&[0xff, 0x25, 0x00, 0x00, 0x00, 0x00]);
+/// code[0x26..0x2e].copy_from_slice(&0x1122_3344_5566_7788u64.to_le_bytes());
+///
+/// let mut proc = DummyOs::quick_process(size::mb(2), &code);
+/// let addr = proc.info().address;
+///
+/// assert_eq!(resolve_rel_branch(&mut proc, addr).unwrap(), addr + 0x15_usize);
+/// assert_eq!(
+///     resolve_rel_branch(&mut proc, addr + 0x20_usize).unwrap(),
+///     Address::from(0x1122_3344_5566_7788u64)
+/// );
+/// ```
+pub fn resolve_rel_branch(mem: &mut impl MemoryView, addr: Address) -> Result<Address> {
+    let mut code = [0u8; 6];
+    mem.read_raw_into(addr, &mut code).data_part()?;
+
+    match code {
+        [0xe8, a, b, c, d, _] | [0xe9, a, b, c, d, _] => {
+            Ok(addr + 5_usize + i32::from_le_bytes([a, b, c, d]))
+        }
+        [0xeb, rel, ..] => Ok(addr + 2_usize + rel as i8),
+        [0xff, 0x25, a, b, c, d] => {
+            let disp = [a, b, c, d];
+            if mem.metadata().arch_bits == 64 {
+                mem.read_addr64(addr + 6_usize + i32::from_le_bytes(disp))
+                    .data_part()
+            } else {
+                mem.read_addr32(u32::from_le_bytes(disp).into()).data_part()
+            }
+        }
+        _ => Err(Error(ErrorOrigin::OsLayer, ErrorKind::NotSupported)),
+    }
+}
+
+/// Encoding of the string searched for by [`search_string`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum StringEncoding {
+    /// Narrow string, the needle is searched for as UTF-8 bytes
+    Ascii,
+    /// Wide string, the needle is searched for as UTF-16 in the byte order of the target
+    Utf16,
+}
+
+/// Size of the blocks in which memory is read by [`search_string`]
+const SEARCH_CHUNK_SIZE: usize = 0x10000;
+
+/// Searches the mapped memory of a process for occurrences of a string.
+///
+/// All mapped regions are scanned and the addresses of all matches are returned.
+/// Occurrences straddling page boundaries are found as well.
+/// Blocks that can not be read are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use memflow::os::util::{search_string, StringEncoding};
+/// # use memflow::dummy::DummyOs;
+/// # use memflow::os::{ModuleInfo, Process};
+/// # use memflow::types::{size, umem, Address};
+///
+/// let mut buf = vec![0u8; 0x2000];
+/// buf[0xffe..0x1002].copy_from_slice(b"flow");
+///
+/// let mut proc = DummyOs::quick_process(size::mb(2), &buf);
+/// let addr = proc.info().address;
+///
+/// // dummy processes report the memory of their modules as mapped
+/// let arch = proc.info().sys_arch;
+/// proc.proc.modules.push(ModuleInfo {
+///     address: Address::null(),
+///     parent_process: addr,
+///     base: addr,
+///     size: size::kb(64) as umem,
+///     name: "dummy.so".into(),
+///     path: "/".into(),
+///     arch,
+/// });
+///
+/// let found = search_string(&mut proc, "flow", StringEncoding::Ascii).unwrap();
+/// assert_eq!(found, vec![addr + 0xffe_usize]);
+/// ```
+pub fn search_string(
+    proc: &mut (impl Process + MemoryView),
+    needle: &str,
+    encoding: StringEncoding,
+) -> Result<Vec<Address>> {
+    let pattern: Vec<u8> = match encoding {
+        StringEncoding::Ascii => needle.as_bytes().to_vec(),
+        StringEncoding::Utf16 => {
+            let little_endian = proc.metadata().little_endian;
+            needle
+                .encode_utf16()
+                .flat_map(|c| {
+                    if little_endian {
+                        c.to_le_bytes()
+                    } else {
+                        c.to_be_bytes()
+                    }
+                })
+                .collect()
+        }
+    };
+
+    if pattern.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut ret = vec![];
+
+    // consecutive reads overlap by the pattern length so matches across chunks are found exactly once
+    let mut buf = vec![0u8; SEARCH_CHUNK_SIZE + pattern.len() - 1];
+    let buf_len = buf.len();
+
+    for range in proc.mapped_mem_vec(0) {
+        let (base, size) = (range.0, range.1 as usize);
+
+        let mut offset = 0;
+        while offset < size {
+            let chunk = &mut buf[..std::cmp::min(size - offset, buf_len)];
+            // skip chunks that can not be read at all, but keep scanning the rest of the region
+            if let Err(e) = proc.read_raw_into(base + offset, chunk).data_part() {
+                log::trace!("Error when reading {:x} {:?}", base + offset, e);
+                offset += SEARCH_CHUNK_SIZE;
+                continue;
+            }
+
+            ret.extend(
+                chunk
+                    .windows(pattern.len())
+                    .enumerate()
+                    .filter(|(_, w)| *w == pattern.as_slice())
+                    .map(|(i, _)| base + offset + i),
+            );
+
+            offset += SEARCH_CHUNK_SIZE;
+        }
+    }
+
+    Ok(ret)
+}
+
+/// Iterates over all readable memory of a process in chunks of at most `step` bytes.
+///
+/// Only mapped memory is visited, the mappings are retrieved upfront via [`Process::mapped_mem`],
+/// so unmapped parts of the address space are skipped without reading them.
+/// The chunks are aligned to multiples of `step` and are truncated at the bounds of the mapped regions.
+/// Chunks that fail to read (even partially) are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use memflow::os::util::iter_readable;
+/// # use memflow::dummy::DummyOs;
+/// # use memflow::os::{ModuleInfo, Process};
+/// # use memflow::types::{size, umem, Address};
+///
+/// let mut proc = DummyOs::quick_process(size::mb(2), &[1, 2, 3, 4]);
+/// let addr = proc.info().address;
+///
+/// // dummy processes report the memory of their modules as mapped
+/// let arch = proc.info().sys_arch;
+/// proc.proc.modules.push(ModuleInfo {
+///     address: Address::null(),
+///     parent_process: addr,
+///     base: addr,
+///     size: size::mb(1) as umem,
+///     name: "dummy.so".into(),
+///     path: "/".into(),
+///     arch,
+/// });
+///
+/// // the entire module is readable
+/// assert_eq!(iter_readable(&mut proc, size::kb(64)).count(), 16);
+///
+/// let (_, data) = iter_readable(&mut proc, size::kb(64))
+///     .find(|(chunk_addr, _)| *chunk_addr == addr)
+///     .unwrap();
+/// assert_eq!(data.len(), size::kb(64));
+/// assert_eq!(data[..4], [1, 2, 3, 4]);
+/// ```
+pub fn iter_readable<'a, P: Process + MemoryView>(
+    proc: &'a mut P,
+    step: usize,
+) -> impl Iterator<Item = (Address, Vec<u8>)> + 'a {
+    let ranges = proc.mapped_mem_vec(0);
+
+    ranges
+        .into_iter()
+        .flat_map(move |range| AddressRange::with_size(range.0, range.1).page_chunks(step))
+        .filter_map(move |chunk| {
+            let mut buf = vec![0u8; chunk.len() as usize];
+            proc.read_raw_into(chunk.start, &mut buf)
+                .ok()
+                .map(|_| (chunk.start, buf))
+        })
+}
+
+const IMAGE_DIRECTORY_ENTRY_BASERELOC: usize = 5;
+const IMAGE_DIRECTORY_ENTRY_IAT: usize = 12;
+
+const IMAGE_SIZEOF_SECTION_HEADER: usize = 0x28;
+const IMAGE_SCN_CNT_CODE: u32 = 0x20;
+const IMAGE_SCN_MEM_EXECUTE: u32 = 0x2000_0000;
+
+const IMAGE_REL_BASED_ABSOLUTE: u16 = 0;
+const IMAGE_REL_BASED_HIGHLOW: u16 = 3;
+const IMAGE_REL_BASED_DIR64: u16 = 10;
+
+/// Upper bound of the clean image size, as SizeOfImage is read from an untrusted file
+const MAX_CLEAN_IMAGE_SIZE: usize = 0x4000_0000;
+
+/// Range of code that differs between the mapped image and a clean copy of it
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct CodePatch {
+    /// Address of the first differing byte in the process
+    pub address: Address,
+    /// Bytes of the clean image, relocated for the module base
+    pub expected: Vec<u8>,
+    /// Bytes currently mapped in the process
+    pub actual: Vec<u8>,
+}
+
+/// PE image file mapped into its memory layout
+struct MappedImage {
+    image: Vec<u8>,
+    image_base: u64,
+    data_directories: Vec<ImageDataDirectory>,
+    /// (virtual address, virtual size) of all executable sections
+    code_sections: Vec<(u32, u32)>,
+}
+
+/// Maps the file layout of a PE image into its memory layout.
+fn map_clean_image(file: &[u8]) -> Result<MappedImage> {
+    let u16_at = |off: usize| {
+        file.get(off..off + 2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+            .ok_or_else(invalid_exe)
+    };
+    let u32_at = |off: usize| {
+        file.get(off..off + 4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .ok_or_else(invalid_exe)
+    };
+
+    if u16_at(0)? != IMAGE_DOS_SIGNATURE {
+        return Err(invalid_exe());
+    }
+    let nt = u32_at(0x3c)? as usize;
+    if u32_at(nt)? != IMAGE_NT_SIGNATURE {
+        return Err(invalid_exe());
+    }
+
+    // offsets of the file and optional headers relative to the NT headers
+    let fh = nt + 0x4;
+    let oh = nt + 0x18;
+
+    let (image_base, dirs_count_offset) = match u16_at(oh)? {
+        IMAGE_NT_OPTIONAL_HDR32_MAGIC => (u32_at(oh + 0x1c)? as u64, oh + 0x5c),
+        IMAGE_NT_OPTIONAL_HDR64_MAGIC => (
+            u32_at(oh + 0x18)? as u64 | (u32_at(oh + 0x1c)? as u64) << 32,
+            oh + 0x6c,
+        ),
+        _ => return Err(invalid_exe()),
+    };
+
+    let dirs_count = std::cmp::min(
+        u32_at(dirs_count_offset)? as usize,
+        IMAGE_NUMBEROF_DIRECTORY_ENTRIES,
+    );
+    let data_directories = (0..dirs_count)
+        .map(|i| dirs_count_offset + 4 + i * 8)
+        .map(|off| {
+            Ok(ImageDataDirectory {
+                virtual_address: u32_at(off)?,
+                size: u32_at(off + 4)?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let size_of_image = u32_at(oh + 0x38)? as usize;
+    if size_of_image == 0 || size_of_image > MAX_CLEAN_IMAGE_SIZE {
+        return Err(invalid_exe());
+    }
+    let size_of_headers = u32_at(oh + 0x3c)? as usize;
+    let number_of_sections = u16_at(fh + 0x2)? as usize;
+    let sections_offset = oh + u16_at(fh + 0x10)? as usize;
+
+    let mut image = vec![0u8; size_of_image];
+
+    let headers_len = std::cmp::min(std::cmp::min(size_of_headers, file.len()), size_of_image);
+    image[..headers_len].copy_from_slice(&file[..headers_len]);
+
+    let mut code_sections = vec![];
+
+    for i in 0..number_of_sections {
+        let sh = sections_offset + i * IMAGE_SIZEOF_SECTION_HEADER;
+        let virtual_size = u32_at(sh + 0x8)? as usize;
+        let virtual_address = u32_at(sh + 0xc)? as usize;
+        let size_of_raw_data = u32_at(sh + 0x10)? as usize;
+        let pointer_to_raw_data = u32_at(sh + 0x14)? as usize;
+        let characteristics = u32_at(sh + 0x24)?;
+
+        let virtual_end =
+            std::cmp::min(virtual_address.saturating_add(virtual_size), size_of_image);
+        if virtual_address >= virtual_end {
+            continue;
+        }
+
+        let raw_len = std::cmp::min(size_of_raw_data, virtual_end - virtual_address);
+        let raw = file
+            .get(pointer_to_raw_data..pointer_to_raw_data.saturating_add(raw_len))
+            .ok_or_else(invalid_exe)?;
+        image[virtual_address..virtual_address + raw.len()].copy_from_slice(raw);
+
+        if characteristics & (IMAGE_SCN_CNT_CODE | IMAGE_SCN_MEM_EXECUTE) != 0 {
+            code_sections.push((
+                virtual_address as u32,
+                (virtual_end - virtual_address) as u32,
+            ));
+        }
+    }
+
+    Ok(MappedImage {
+        image,
+        image_base,
+        data_directories,
+        code_sections,
+    })
+}
+
+/// Applies the base relocations of a mapped image for the given base delta.
+fn relocate_image(image: &mut [u8], reloc_dir: &ImageDataDirectory, delta: u64) {
+    use core::convert::TryInto;
+
+    let start = reloc_dir.virtual_address as usize;
+    let end = std::cmp::min(start.saturating_add(reloc_dir.size as usize), image.len());
+
+    let mut block = start;
+    while block + 8 <= end {
+        let page_rva = u32::from_le_bytes(image[block..block + 4].try_into().unwrap()) as usize;
+        let block_size =
+            u32::from_le_bytes(image[block + 4..block + 8].try_into().unwrap()) as usize;
+        if block_size < 8 {
+            break;
+        }
+
+        let block_end = std::cmp::min(block + block_size, end);
+        for entry in (block + 8..block_end).step_by(2) {
+            if entry + 2 > block_end {
+                break;
+            }
+            let entry = u16::from_le_bytes([image[entry], image[entry + 1]]);
+            let off = page_rva + (entry & 0xfff) as usize;
+
+            match entry >> 12 {
+                IMAGE_REL_BASED_ABSOLUTE => {}
+                IMAGE_REL_BASED_HIGHLOW if off + 4 <= image.len() => {
+                    let value = u32::from_le_bytes(image[off..off + 4].try_into().unwrap());
+                    image[off..off + 4]
+                        .copy_from_slice(&value.wrapping_add(delta as u32).to_le_bytes());
+                }
+                IMAGE_REL_BASED_DIR64 if off + 8 <= image.len() => {
+                    let value = u64::from_le_bytes(image[off..off + 8].try_into().unwrap());
+                    image[off..off + 8].copy_from_slice(&value.wrapping_add(delta).to_le_bytes());
+                }
+                ty => log::trace!("unsupported relocation type {} at rva {:x}", ty, off),
+            }
+        }
+
+        block += block_size;
+    }
+}
+
+/// Compares the executable sections of a mapped module against a clean copy of its file.
+///
+/// `clean_image` is the unmodified file as stored on disk. Its sections are mapped to their
+/// virtual addresses and the base relocations are applied for the actual base of the module,
+/// so that only modifications made at runtime (e.g. inline hooks) are reported.
+/// Differences within the import address table, which the loader fills in, are ignored.
+///
+/// Adjacent differing bytes are merged into a single [`CodePatch`].
+///
+/// # Remarks
+///
+/// Pages that can not be read (e.g. because they are paged out) are skipped.
+/// The clean image has to match the mapped module, which can be verified
+/// by comparing [`pe_info`] against the headers of the file.
+pub fn diff_against_clean(
+    mem: &mut impl MemoryView,
+    info: &ModuleInfo,
+    clean_image: &[u8],
+) -> Result<Vec<CodePatch>> {
+    let MappedImage {
+        mut image,
+        image_base,
+        data_directories,
+        code_sections,
+    } = map_clean_image(clean_image)?;
+
+    if let Some(reloc_dir) = data_directories.get(IMAGE_DIRECTORY_ENTRY_BASERELOC) {
+        let delta = info.base.to_umem().wrapping_sub(image_base as umem) as u64;
+        if delta != 0 {
+            relocate_image(&mut image, reloc_dir, delta);
+        }
+    }
+
+    let iat = data_directories
+        .get(IMAGE_DIRECTORY_ENTRY_IAT)
+        .map(|dir| {
+            AddressRange::with_size(info.base + dir.virtual_address as umem, dir.size as umem)
+        })
+        .unwrap_or_default();
+
+    let mut patches: Vec<CodePatch> = vec![];
+    let mut page = vec![0u8; 0x1000];
+
+    for (virtual_address, virtual_size) in code_sections {
+        let section =
+            AddressRange::with_size(info.base + virtual_address as umem, virtual_size as umem);
+
+        for chunk in section.page_chunks(page.len()) {
+            let actual = &mut page[..chunk.len() as usize];
+            if mem.read_raw_into(chunk.start, actual).is_err() {
+                continue;
+            }
+
+            let rva = chunk.start.to_umem() - info.base.to_umem();
+            let expected = &image[rva as usize..rva as usize + actual.len()];
+
+            for (i, (&e, &a)) in expected.iter().zip(actual.iter()).enumerate() {
+                let addr = chunk.start + i;
+                if e == a || iat.contains(addr) {
+                    continue;
+                }
+
+                match patches.last_mut() {
+                    Some(patch) if patch.address + patch.actual.len() == addr => {
+                        patch.expected.push(e);
+                        patch.actual.push(a);
+                    }
+                    _ => patches.push(CodePatch {
+                        address: addr,
+                        expected: vec![e],
+                        actual: vec![a],
+                    }),
+                }
+            }
+        }
+    }
+
+    Ok(patches)
+}
+
+/// Maximum number of forwarders that are followed when resolving an export
+#[cfg(feature = "pelite")]
+const MAX_FORWARDER_DEPTH: usize = 16;
+
+#[cfg(feature = "pelite")]
+enum ExportTarget {
+    Symbol(umem),
+    Forward(String),
+}
+
+#[cfg(feature = "pelite")]
+fn export_target(mem: &mut impl MemoryView, info: &ModuleInfo, name: &str) -> Result<ExportTarget> {
+    let mut module_image = aligned_alloc(info.size as usize);
+    let module_image = module_image.as_bytes_mut();
+
+    mem.read_raw_into(info.base, module_image).data_part()?;
+
+    let pe = pelite::PeView::from_bytes(module_image).map_err(|_| invalid_exe())?;
+
+    use pelite::pe64::exports::Export;
+
+    let exports = pe
+        .exports()
+        .and_then(|e| e.by())
+        .map_err(|e| log::debug!("pelite: {}", e))
+        .map_err(|_| Error(ErrorOrigin::OsLayer, ErrorKind::ExportNotFound))?;
+
+    let target = exports
+        .iter_names()
+        .filter_map(|(n, e)| n.ok().zip(e.ok()))
+        .find(|(n, _)| n.to_str().ok() == Some(name))
+        .and_then(|(_, e)| match e {
+            Export::Symbol(off) => Some(ExportTarget::Symbol(*off as umem)),
+            Export::Forward(fwd) => fwd.to_str().ok().map(|f| ExportTarget::Forward(f.into())),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        });
+
+    target.ok_or(Error(ErrorOrigin::OsLayer, ErrorKind::ExportNotFound))
+}
+
+/// Resolves the address of an export while following forwarders into other modules.
+///
+/// Forwarded exports (e.g. `NTDLL.RtlAllocateHeap`) are resolved by looking up the target
+/// module in the process (case-insensitive, with an implied `.dll` extension) and resolving the
+/// export there. Chains of forwarders are followed up to a fixed depth which also guards against cycles.
+///
+/// # Remarks
+///
+/// Forwarders by ordinal and forwarders into api sets (`api-ms-win-*`) are not resolved.
+#[cfg(feature = "pelite")]
+pub fn resolve_export_forwarded(
+    proc: &mut (impl Process + MemoryView),
+    info: &ModuleInfo,
+    name: &str,
+) -> Result<Address> {
+    let mut info = info.clone();
+    let mut name = name.to_string();
+
+    for _ in 0..MAX_FORWARDER_DEPTH {
+        match export_target(proc, &info, &name)? {
+            ExportTarget::Symbol(offset) => return Ok(info.base + offset),
+            ExportTarget::Forward(forward) => {
+                let (module_name, export_name) = forward
+                    .rsplit_once('.')
+                    .ok_or(Error(ErrorOrigin::OsLayer, ErrorKind::ExportNotFound))?;
+                let module_dll = format!("{}.dll", module_name);
+
+                let mut target = None;
+                let callback = &mut |m: ModuleInfo| {
+                    let m_name: &str = m.name.as_ref();
+                    if m_name.eq_ignore_ascii_case(module_name)
+                        || m_name.eq_ignore_ascii_case(&module_dll)
+                    {
+                        target = Some(m);
+                        false
+                    } else {
+                        true
+                    }
+                };
+                proc.module_list_callback(Some(&info.arch), callback.into())?;
+
+                info = target.ok_or(Error(ErrorOrigin::OsLayer, ErrorKind::ModuleNotFound))?;
+                name = export_name.to_string();
+            }
+        }
+    }
+
+    Err(Error(ErrorOrigin::OsLayer, ErrorKind::ExportNotFound))
+}
+
 #[inline]
 pub fn module_section_list_callback(
     mem: &mut impl MemoryView,
@@ -20,15 +20,13 @@ use cglue::trait_group::c_void;
 cglue_trait_group!(ConnectorInstance, { PhysicalMemory, Clone }, { ConnectorCpuState });
 pub type MuConnectorInstanceArcBox<'a> = std::mem::MaybeUninit<ConnectorInstanceArcBox<'a>>;
 
-/// This creates a cglue plugin instance from the given [`PhysicalMemory`] object.
-/// This also configures caching based on the provided input `args`.
-pub fn create_instance<T: Send + 'static + PhysicalMemory>(
+/// Wraps the connector with the [`CachedPhysicalMemory`] middleware if caching is enabled.
+fn insert_cache<T: Send + 'static + PhysicalMemory>(
     conn: T,
     lib: LibArc,
     args: &ConnectorArgs,
     no_default_cache: bool,
 ) -> ConnectorInstanceArcBox<'static>
-// TODO: get rid of these trait bounds
 where
     (T, LibArc): Into<ConnectorInstanceBaseArcBox<'static, T, c_void>>,
     (
@@ -44,7 +42,7 @@ where
 {
     // check if user explicitly enabled caching or alternatively fall back to auto configuration of the connector
     let use_cache = Option::<bool>::from(args.middleware_args.cache).unwrap_or(!no_default_cache);
-    let conn = if use_cache {
+    if use_cache {
         let cache_page_size = if args.middleware_args.cache_page_size > 0 {
             args.middleware_args.cache_page_size
         } else {
@@ -67,9 +65,69 @@ where
         }
 
         let conn = builder.build().unwrap();
-        group_obj!((conn, lib.clone()) as ConnectorInstance)
+        group_obj!((conn, lib) as ConnectorInstance)
+    } else {
+        group_obj!((conn, lib) as ConnectorInstance)
+    }
+}
+
+/// This creates a cglue plugin instance from the given [`PhysicalMemory`] object.
+/// This also configures caching based on the provided input `args`.
+pub fn create_instance<T: Send + 'static + PhysicalMemory>(
+    conn: T,
+    lib: LibArc,
+    args: &ConnectorArgs,
+    no_default_cache: bool,
+) -> ConnectorInstanceArcBox<'static>
+// TODO: get rid of these trait bounds
+where
+    (T, LibArc): Into<ConnectorInstanceBaseArcBox<'static, T, c_void>>,
+    (
+        CachedPhysicalMemory<'static, T, TimedCacheValidator>,
+        LibArc,
+    ): Into<
+        ConnectorInstanceBaseArcBox<
+            'static,
+            CachedPhysicalMemory<'static, T, TimedCacheValidator>,
+            c_void,
+        >,
+    >,
+    (ChunkedPhysicalMemory<T>, LibArc):
+        Into<ConnectorInstanceBaseArcBox<'static, ChunkedPhysicalMemory<T>, c_void>>,
+    (
+        CachedPhysicalMemory<'static, ChunkedPhysicalMemory<T>, TimedCacheValidator>,
+        LibArc,
+    ): Into<
+        ConnectorInstanceBaseArcBox<
+            'static,
+            CachedPhysicalMemory<'static, ChunkedPhysicalMemory<T>, TimedCacheValidator>,
+            c_void,
+        >,
+    >,
+{
+    // the chunked middleware is applied to the connector itself so that the page reads of the cache are split up as well
+    let conn = if args.middleware_args.max_transfer > 0 {
+        info!(
+            "Inserting `ChunkedPhysicalMemory` middleware with max_transfer={}",
+            args.middleware_args.max_transfer
+        );
+
+        let conn = ChunkedPhysicalMemory::new(conn, args.middleware_args.max_transfer);
+        insert_cache(conn, lib.clone(), args, no_default_cache)
     } else {
+        insert_cache(conn, lib.clone(), args, no_default_cache)
+    };
+
+    let conn = if args.middleware_args.read_align > 0 {
+        info!(
+            "Inserting `AlignedPhysicalMemory` middleware with read_align={}",
+            args.middleware_args.read_align
+        );
+
+        let conn = AlignedPhysicalMemory::new(conn, args.middleware_args.read_align);
         group_obj!((conn, lib.clone()) as ConnectorInstance)
+    } else {
+        conn
     };
 
     let conn = if args.middleware_args.delay > 0 {
@@ -107,6 +165,10 @@ pub struct ConnectorMiddlewareArgs {
     pub cache_validity_time: u64,
     pub cache_page_size: usize,
 
+    pub max_transfer: usize,
+
+    pub read_align: usize,
+
     pub delay: u64,
 
     pub metrics: bool,
@@ -134,6 +196,16 @@ impl ConnectorMiddlewareArgs {
         self
     }
 
+    pub fn max_transfer(mut self, max_transfer: usize) -> Self {
+        self.max_transfer = max_transfer;
+        self
+    }
+
+    pub fn read_align(mut self, read_align: usize) -> Self {
+        self.read_align = read_align;
+        self
+    }
+
     pub fn delay(mut self, delay: u64) -> Self {
         self.delay = delay;
         self
@@ -199,6 +271,18 @@ impl std::str::FromStr for ConnectorMiddlewareArgs {
                 .log_error("Failed to parse Page size for an entry")
         })?;
 
+        let max_transfer = usize::from_str_radix(args.get("max_transfer").unwrap_or("0"), 16)
+            .map_err(|_| {
+                Error(ErrorOrigin::OsLayer, ErrorKind::Configuration)
+                    .log_error("Failed to parse max transfer size")
+            })?;
+
+        let read_align =
+            usize::from_str_radix(args.get("read_align").unwrap_or("0"), 16).map_err(|_| {
+                Error(ErrorOrigin::OsLayer, ErrorKind::Configuration)
+                    .log_error("Failed to parse read alignment")
+            })?;
+
         let delay = args
             .get("delay")
             .unwrap_or("0")
@@ -219,6 +303,10 @@ impl std::str::FromStr for ConnectorMiddlewareArgs {
             cache_validity_time,
             cache_page_size,
 
+            max_transfer,
+
+            read_align,
+
             delay,
 
             metrics,
@@ -3,6 +3,7 @@ Abstraction over a address on the target system.
 */
 
 use super::{PhysicalAddress, Pointer};
+use crate::architecture::ArchitectureObj;
 use crate::types::ByteSwap;
 
 use core::convert::TryInto;
@@ -10,6 +11,7 @@ use std::default::Default;
 use std::fmt;
 use std::hash;
 use std::ops;
+use std::prelude::v1::*;
 
 /// The largest target memory type
 /// The following core rule is defined for these memory types:
@@ -280,6 +282,25 @@ impl Address {
         self.0 == 0
     }
 
+    /// Checks wether the address lies within the first page of memory.
+    ///
+    /// Pointers into the null page are practically never valid and usually
+    /// are the result of a small offset being added to a null pointer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::types::Address;
+    ///
+    /// assert_eq!(Address::null().is_null_page(0x1000), true);
+    /// assert_eq!(Address::from(0x18u64).is_null_page(0x1000), true);
+    /// assert_eq!(Address::from(0x1000u64).is_null_page(0x1000), false);
+    /// ```
+    #[inline]
+    pub const fn is_null_page(self, page_size: usize) -> bool {
+        self.0 < page_size as umem
+    }
+
     /// Converts the address to an Option that is None when it is null
     ///
     /// # Examples
@@ -409,6 +430,90 @@ impl Address {
     pub const fn wrapping_sub(self, other: Self) -> Self {
         Self(self.0.wrapping_sub(other.0))
     }
+
+    /// Returns the signed distance of this address from `base`.
+    ///
+    /// `None` is returned if the distance does not fit into an `imem`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::types::Address;
+    ///
+    /// let base = Address::from(0x1000);
+    /// assert_eq!(Address::from(0x1010).offset_from(base), Some(0x10));
+    /// assert_eq!(Address::from(0xff0).offset_from(base), Some(-0x10));
+    /// assert_eq!(Address::invalid().offset_from(Address::null()), None);
+    /// ```
+    pub fn offset_from(self, base: Self) -> Option<imem> {
+        if self.0 >= base.0 {
+            (self.0 - base.0).try_into().ok()
+        } else {
+            (base.0 - self.0)
+                .try_into()
+                .ok()
+                .map(|distance: imem| -distance)
+        }
+    }
+
+    /// Returns the absolute distance between this address and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::types::Address;
+    ///
+    /// let base = Address::from(0x1000);
+    /// assert_eq!(Address::from(0x1010).distance_to(base), 0x10);
+    /// assert_eq!(base.distance_to(Address::from(0x1010)), 0x10);
+    /// ```
+    pub const fn distance_to(self, other: Self) -> umem {
+        if self.0 >= other.0 {
+            self.0 - other.0
+        } else {
+            other.0 - self.0
+        }
+    }
+
+    /// Returns the base address of the structure containing the field at this address.
+    ///
+    /// This is the equivalent of the `CONTAINING_RECORD` / `container_of` macros and is
+    /// mostly used when walking intrusive lists (e.g. `LIST_ENTRY`) embedded into larger structures.
+    /// A null address, or an offset that would underflow, results in a null address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::types::Address;
+    ///
+    /// let link = Address::from(0x1048);
+    /// assert_eq!(link.container_of(0x48), Address::from(0x1000));
+    /// assert_eq!(Address::from(0x10).container_of(0x48), Address::null());
+    /// assert_eq!(Address::null().container_of(0), Address::null());
+    /// ```
+    pub const fn container_of(self, field_offset: usize) -> Self {
+        if self.is_null() || (field_offset as umem) > self.0 {
+            Self::null()
+        } else {
+            Self(self.0 - field_offset as umem)
+        }
+    }
+
+    /// Formats the address as a hex string zero-padded to the pointer width of the given architecture.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::types::Address;
+    /// use memflow::architecture::x86::{x32, x64};
+    ///
+    /// let addr = Address::from(0x401000);
+    /// assert_eq!(addr.format_for_arch(x32::ARCH), "0x00401000");
+    /// assert_eq!(addr.format_for_arch(x64::ARCH), "0x0000000000401000");
+    /// ```
+    pub fn format_for_arch(self, arch: ArchitectureObj) -> String {
+        format!("{:#0width$x}", self.0, width = arch.size_addr() * 2 + 2)
+    }
 }
 
 /// Returns a address with a value of zero.
@@ -683,6 +788,13 @@ mod tests {
         assert!(!Address::invalid().is_valid());
     }
 
+    #[test]
+    fn test_null_page() {
+        assert!(Address::from(0xfffu64).is_null_page(size::kb(4)));
+        assert!(!Address::from(0x1000u64).is_null_page(size::kb(4)));
+        assert!(!Address::invalid().is_null_page(size::kb(4)));
+    }
+
     #[test]
     fn test_from() {
         assert_eq!(Address::from(1337_u32).to_umem(), 1337);
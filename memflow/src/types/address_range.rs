@@ -0,0 +1,203 @@
+/*!
+Abstraction over a range of addresses.
+*/
+
+use super::{umem, Address};
+
+use core::fmt;
+
+/// This type represents a half-open range of addresses (`start..end`).
+///
+/// The `end` address is exclusive, a range with `start == end` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use memflow::types::{Address, AddressRange};
+///
+/// let range = AddressRange::with_size(Address::from(0x1000), 0x2000);
+/// assert!(range.contains(Address::from(0x2fff)));
+/// assert!(!range.contains(Address::from(0x3000)));
+/// assert_eq!(range.len(), 0x2000);
+/// ```
+#[repr(C)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "abi_stable", derive(::abi_stable::StableAbi))]
+pub struct AddressRange {
+    pub start: Address,
+    pub end: Address,
+}
+
+impl AddressRange {
+    /// Constructs a new range from `start` to `end` (exclusive).
+    ///
+    /// If `end` is smaller than `start` the resulting range is empty.
+    pub fn new(start: Address, end: Address) -> Self {
+        Self {
+            start,
+            end: core::cmp::max(start, end),
+        }
+    }
+
+    /// Constructs a new range that starts at `start` and spans `size` bytes.
+    ///
+    /// The end of the range is clamped at the top of the address space.
+    pub fn with_size(start: Address, size: umem) -> Self {
+        Self {
+            start,
+            end: start.to_umem().saturating_add(size).into(),
+        }
+    }
+
+    /// Returns the number of bytes in the range.
+    pub fn len(&self) -> umem {
+        self.end.to_umem().saturating_sub(self.start.to_umem())
+    }
+
+    /// Returns `true` if the range does not contain any address.
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// Returns `true` if `addr` lies within the range.
+    pub fn contains(&self, addr: Address) -> bool {
+        self.start <= addr && addr < self.end
+    }
+
+    /// Returns `true` if both ranges share at least one address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::types::{Address, AddressRange};
+    ///
+    /// let a = AddressRange::new(Address::from(0x1000), Address::from(0x2000));
+    /// let b = AddressRange::new(Address::from(0x1fff), Address::from(0x3000));
+    /// let c = AddressRange::new(Address::from(0x2000), Address::from(0x3000));
+    /// assert!(a.overlaps(&b));
+    /// assert!(!a.overlaps(&c));
+    /// ```
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// Returns the part of this range that lies within `other`.
+    ///
+    /// `None` is returned if the ranges do not overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::types::{Address, AddressRange};
+    ///
+    /// let range = AddressRange::new(Address::from(0x1000), Address::from(0x3000));
+    /// let window = AddressRange::new(Address::from(0x2000), Address::from(0x4000));
+    /// assert_eq!(
+    ///     range.clamp(&window),
+    ///     Some(AddressRange::new(Address::from(0x2000), Address::from(0x3000)))
+    /// );
+    /// ```
+    pub fn clamp(&self, other: &Self) -> Option<Self> {
+        if self.overlaps(other) {
+            Some(Self {
+                start: core::cmp::max(self.start, other.start),
+                end: core::cmp::min(self.end, other.end),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the sub-ranges of this range split at page boundaries.
+    ///
+    /// The first and last sub-range are not page aligned if the range itself is not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::types::{size, Address, AddressRange};
+    ///
+    /// let range = AddressRange::new(Address::from(0x1800), Address::from(0x3800));
+    /// let pages = range.page_chunks(size::kb(4)).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(pages.len(), 3);
+    /// assert_eq!(pages[0], AddressRange::new(Address::from(0x1800), Address::from(0x2000)));
+    /// assert_eq!(pages[2], AddressRange::new(Address::from(0x3000), Address::from(0x3800)));
+    /// ```
+    pub fn page_chunks(&self, page_size: usize) -> impl Iterator<Item = AddressRange> {
+        let page_size = core::cmp::max(page_size, 1) as umem;
+        let end = self.end;
+        let mut cur = self.start;
+
+        core::iter::from_fn(move || {
+            if cur >= end {
+                return None;
+            }
+
+            let next_page = cur
+                .as_mem_aligned(page_size)
+                .to_umem()
+                .saturating_add(page_size);
+            let chunk_end = core::cmp::min(Address::from(next_page), end);
+
+            let chunk = AddressRange {
+                start: cur,
+                end: chunk_end,
+            };
+            cur = chunk_end;
+            Some(chunk)
+        })
+    }
+}
+
+impl fmt::Debug for AddressRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:x}..{:x}", self.start, self.end)
+    }
+}
+
+impl From<core::ops::Range<Address>> for AddressRange {
+    fn from(range: core::ops::Range<Address>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        let range = AddressRange::new(Address::from(0x2000), Address::from(0x1000));
+        assert!(range.is_empty());
+        assert_eq!(range.len(), 0);
+        assert!(!range.contains(Address::from(0x2000)));
+        assert!(!range.overlaps(&range));
+    }
+
+    #[test]
+    fn test_inverted_fields() {
+        let range = AddressRange {
+            start: Address::from(0x2000),
+            end: Address::from(0x1000),
+        };
+        assert!(range.is_empty());
+        assert_eq!(range.len(), 0);
+    }
+
+    #[test]
+    fn test_with_size_saturates() {
+        let range = AddressRange::with_size(Address::invalid(), 0x1000);
+        assert!(range.is_empty());
+    }
+
+    #[test]
+    fn test_page_chunks_aligned() {
+        let range = AddressRange::with_size(Address::from(0x1000), 0x3000);
+        let pages = range.page_chunks(0x1000).collect::<Vec<_>>();
+        assert_eq!(pages.len(), 3);
+        assert!(pages.iter().all(|p| p.len() == 0x1000));
+        assert_eq!(pages[2].end, range.end);
+    }
+}
@@ -150,6 +150,13 @@ impl<T: ByteSwap> ByteSwap for [T] {
     }
 }
 
+// array types
+impl<T: ByteSwap, const N: usize> ByteSwap for [T; N] {
+    fn byte_swap(&mut self) {
+        self.iter_mut().for_each(|e| e.byte_swap());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -252,4 +259,19 @@ mod tests {
         slice.byte_swap();
         assert_eq!(slice[0], 1234);
     }
+
+    #[test]
+    fn swap_array_pointer32() {
+        use crate::types::Pointer32;
+
+        let mut arr = [
+            Pointer32::<u32>::from(0x1234u32),
+            Pointer32::from(0x5678u32),
+        ];
+        arr.byte_swap();
+        assert_eq!(arr[0], Pointer32::from(0x1234u32.swap_bytes()));
+        assert_eq!(arr[1], Pointer32::from(0x5678u32.swap_bytes()));
+        arr.byte_swap();
+        assert_eq!(arr[0], Pointer32::from(0x1234u32));
+    }
 }
@@ -9,6 +9,9 @@ pub use address::{
     clamp_to_isize, clamp_to_usize, imem, umem, Address, PrimitiveAddress, UMEM_BITS,
 };
 
+pub mod address_range;
+pub use address_range::AddressRange;
+
 mod mem_units;
 pub use mem_units::*;
 
@@ -19,7 +22,7 @@ pub mod physical_address;
 pub use physical_address::PhysicalAddress;
 
 pub mod pointer;
-pub use pointer::{Pointer, Pointer32, Pointer64};
+pub use pointer::{CachedPointer, CachedPointer32, CachedPointer64, Pointer, Pointer32, Pointer64};
 
 pub mod byte_swap;
 pub use byte_swap::ByteSwap;
@@ -2,10 +2,11 @@
 Pointer abstraction.
 */
 
-use crate::cglue::ReprCString;
-use crate::dataview::Pod;
-use crate::error::{PartialError, PartialResult};
-use crate::mem::MemoryView;
+use crate::architecture::{ArchitectureObj, Endianess};
+use crate::cglue::{CSliceMut, CTup2, ReprCString};
+use crate::dataview::{Pod, PodMethods};
+use crate::error::{Error, ErrorKind, ErrorOrigin, PartialError, PartialResult, PartialResultExt};
+use crate::mem::{MemoryView, ReadData};
 use crate::types::{imem, umem, Address, ByteSwap, PrimitiveAddress};
 
 use std::convert::TryInto;
@@ -292,13 +293,77 @@ impl<U: PrimitiveAddress, T: Pod + ?Sized> Pointer<U, T> {
     pub fn read_into<M: MemoryView>(self, mem: &mut M, out: &mut T) -> PartialResult<()> {
         mem.read_ptr_into(self, out)
     }
+
+    /// Reads into `out` unless the pointer is null.
+    ///
+    /// Returns `false` without reading anything if the pointer is null.
+    pub fn try_read_into<M: MemoryView>(self, mem: &mut M, out: &mut T) -> PartialResult<bool> {
+        if self.is_null() {
+            Ok(false)
+        } else {
+            self.read_into(mem, out).map_data(|_| true)
+        }
+    }
 }
 
 impl<U: PrimitiveAddress, T: Pod + Sized> Pointer<U, T> {
+    /// Reads the value in the byte order of the host.
+    ///
+    /// Use [`read_arch`](Self::read_arch) when the target architecture might differ in endianess.
     pub fn read<M: MemoryView>(self, mem: &mut M) -> PartialResult<T> {
         mem.read_ptr(self)
     }
 
+    /// Reads the value in the byte order of the given architecture.
+    ///
+    /// The value is byte swapped if the endianess of `arch` differs from the host.
+    pub fn read_arch<M: MemoryView>(self, mem: &mut M, arch: ArchitectureObj) -> PartialResult<T>
+    where
+        T: ByteSwap,
+    {
+        let swap = (arch.endianess() == Endianess::LittleEndian) != cfg!(target_endian = "little");
+
+        self.read(mem).map_data(|mut v| {
+            if swap {
+                v.byte_swap();
+            }
+            v
+        })
+    }
+
+    /// Reads the value unless the pointer is null.
+    ///
+    /// This is useful for walking nullable pointer fields, as a null pointer
+    /// results in `None` instead of a failing read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::types::{Address, Pointer64};
+    /// use memflow::mem::MemoryView;
+    ///
+    /// fn read_optional(mem: &mut impl MemoryView, addr: Address) {
+    ///     let ptr = Pointer64::<u32>::from(addr);
+    ///     assert_eq!(ptr.try_read(mem).unwrap(), Some(0x04030201));
+    ///
+    ///     assert_eq!(Pointer64::<u32>::null().try_read(mem).unwrap(), None);
+    /// }
+    ///
+    /// # use memflow::types::size;
+    /// # use memflow::dummy::DummyOs;
+    /// # use memflow::os::Process;
+    /// # let mut proc = DummyOs::quick_process(size::mb(2), &[1, 2, 3, 4]);
+    /// # let addr = proc.info().address;
+    /// # read_optional(&mut proc, addr);
+    /// ```
+    pub fn try_read<M: MemoryView>(self, mem: &mut M) -> PartialResult<Option<T>> {
+        if self.is_null() {
+            Ok(None)
+        } else {
+            self.read(mem).map_data(Some)
+        }
+    }
+
     pub fn write<M: MemoryView>(self, mem: &mut M, data: &T) -> PartialResult<()> {
         mem.write_ptr(self, data)
     }
@@ -320,6 +385,77 @@ impl<U: PrimitiveAddress> Pointer<U, ReprCString> {
     }
 }
 
+impl<U: PrimitiveAddress, T: Pod> Pointer<U, [T]> {
+    /// Reads consecutive elements into a caller-owned slice.
+    ///
+    /// On success the number of elements in `out` is returned. In case of a partial read
+    /// [`PartialError::PartialVirtualRead`] holds the number of leading elements that were
+    /// fully read. All elements after that are zeroed out.
+    ///
+    /// Zero-sized element types are rejected with [`ErrorKind::InvalidArgument`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use memflow::types::{Address, Pointer64};
+    /// use memflow::mem::MemoryView;
+    ///
+    /// fn read_records(mem: &mut impl MemoryView, addr: Address) {
+    ///     let ptr = Pointer64::<[u32]>::from(addr);
+    ///
+    ///     // the buffer can be reused across reads
+    ///     let mut buf = [0u32; 4];
+    ///     assert_eq!(ptr.read_into_slice(mem, &mut buf), Ok(4));
+    ///     assert_eq!(buf, [1, 2, 3, 4]);
+    /// }
+    ///
+    /// # use memflow::types::size;
+    /// # use memflow::dummy::DummyOs;
+    /// # use memflow::os::Process;
+    /// # let buf = [1u32, 2, 3, 4].iter().flat_map(|v| v.to_le_bytes()).collect::<Vec<u8>>();
+    /// # let mut proc = DummyOs::quick_process(size::mb(2), &buf);
+    /// # let addr = proc.info().address;
+    /// # read_records(&mut proc, addr);
+    /// ```
+    pub fn read_into_slice<M: MemoryView>(
+        self,
+        mem: &mut M,
+        out: &mut [T],
+    ) -> PartialResult<usize> {
+        if size_of::<T>() == 0 {
+            return Err(PartialError::Error(Error(
+                ErrorOrigin::Memory,
+                ErrorKind::InvalidArgument,
+            )));
+        }
+
+        let addr = self.address();
+        let mut first_fail: Option<Address> = None;
+
+        let callback = &mut |CTup2(fail_addr, mut data): ReadData| {
+            first_fail = Some(first_fail.map_or(fail_addr, |a| cmp::min(a, fail_addr)));
+            data.iter_mut().for_each(|b| *b = 0);
+            true
+        };
+
+        mem.read_iter(
+            Some(CTup2(addr, CSliceMut::from(out.as_bytes_mut()))).into_iter(),
+            None,
+            Some(&mut callback.into()),
+        )?;
+
+        match first_fail {
+            None => Ok(out.len()),
+            Some(fail_addr) => {
+                let count = (fail_addr - addr) as usize / size_of::<T>();
+                // zero out all elements that have only been partially read
+                out[count..].as_bytes_mut().iter_mut().for_each(|b| *b = 0);
+                Err(PartialError::PartialVirtualRead(count))
+            }
+        }
+    }
+}
+
 impl<U: PrimitiveAddress, T> Pointer<U, [T]> {
     pub fn decay(self) -> Pointer<U, T> {
         Pointer {
@@ -490,6 +626,96 @@ impl<U: PrimitiveAddress, T: ?Sized + 'static> ByteSwap for Pointer<U, T> {
     }
 }
 
+pub type CachedPointer32<T> = CachedPointer<u32, T>;
+pub type CachedPointer64<T> = CachedPointer<u64, T>;
+
+/// Wrapper around a [`Pointer`] which memoizes the last value read through it.
+///
+/// This is useful for pointers which are dereferenced frequently but whose
+/// pointee rarely changes (e.g. a global singleton). The cached value is returned
+/// until [`invalidate`](Self::invalidate) is called.
+///
+/// # Examples
+///
+/// ```
+/// use memflow::types::{CachedPointer64, Pointer64};
+/// use memflow::mem::MemoryView;
+///
+/// fn read_twice(mem: &mut impl MemoryView, addr: u64) {
+///     let mut ptr = CachedPointer64::<u64>::from(Pointer64::from(addr));
+///
+///     let first = *ptr.read(mem).unwrap();
+///     // the second read is served from the cache
+///     let second = *ptr.read(mem).unwrap();
+///     assert_eq!(first, second);
+///
+///     // forces the next read to go to memory again
+///     ptr.invalidate();
+///     assert!(!ptr.is_cached());
+/// }
+///
+/// # use memflow::types::size;
+/// # use memflow::dummy::DummyOs;
+/// # use memflow::os::Process;
+/// # let mut proc = DummyOs::quick_process(size::mb(2), &[]);
+/// # let addr = proc.info().address.to_umem() as u64;
+/// # read_twice(&mut proc, addr);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CachedPointer<U: PrimitiveAddress, T: Sized> {
+    ptr: Pointer<U, T>,
+    value: Option<T>,
+}
+
+impl<U: PrimitiveAddress, T: Pod + Sized> CachedPointer<U, T> {
+    /// Constructs a new cached pointer with an empty cache.
+    pub fn new(ptr: Pointer<U, T>) -> Self {
+        Self { ptr, value: None }
+    }
+
+    /// Returns the underlying pointer.
+    #[inline]
+    pub fn pointer(&self) -> Pointer<U, T> {
+        self.ptr
+    }
+
+    /// Returns `true` if a value is currently cached.
+    #[inline]
+    pub fn is_cached(&self) -> bool {
+        self.value.is_some()
+    }
+
+    /// Drops the cached value so the next read will go to memory again.
+    #[inline]
+    pub fn invalidate(&mut self) {
+        self.value = None;
+    }
+
+    /// Returns the cached value or reads it from memory if the cache is empty.
+    ///
+    /// Only successful reads are stored in the cache.
+    pub fn read<M: MemoryView>(&mut self, mem: &mut M) -> PartialResult<&T> {
+        if self.value.is_none() {
+            self.value = Some(self.ptr.read(mem)?);
+        }
+        Ok(self.value.as_ref().unwrap())
+    }
+}
+
+impl<U: PrimitiveAddress, T: Pod + Sized> From<Pointer<U, T>> for CachedPointer<U, T> {
+    #[inline]
+    fn from(ptr: Pointer<U, T>) -> Self {
+        Self::new(ptr)
+    }
+}
+
+impl<U: PrimitiveAddress, T: Pod + Sized> From<U> for CachedPointer<U, T> {
+    #[inline]
+    fn from(address: U) -> Self {
+        Self::new(address.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -536,4 +762,67 @@ mod tests {
         assert_eq!(ptr2.offset_from(ptr1), 4);
         assert_eq!(ptr1.offset_from(ptr2), -4);
     }
+
+    #[test]
+    fn cached_pointer() {
+        use crate::dummy::DummyOs;
+        use crate::os::Process;
+        use crate::types::size;
+
+        let mut proc = DummyOs::quick_process(size::mb(2), &[]);
+        let addr = proc.info().address;
+        proc.write(addr, &0x1234u64).unwrap();
+
+        let mut ptr = CachedPointer64::<u64>::from(Pointer64::from(addr));
+        assert!(!ptr.is_cached());
+        assert_eq!(*ptr.read(&mut proc).unwrap(), 0x1234);
+        assert!(ptr.is_cached());
+
+        // the cached value is kept even though the memory changed
+        proc.write(addr, &0x5678u64).unwrap();
+        assert_eq!(*ptr.read(&mut proc).unwrap(), 0x1234);
+
+        ptr.invalidate();
+        assert_eq!(*ptr.read(&mut proc).unwrap(), 0x5678);
+    }
+
+    #[test]
+    fn read_arch() {
+        use crate::architecture::x86::x64;
+        use crate::dummy::DummyOs;
+        use crate::os::Process;
+        use crate::types::size;
+
+        let mut proc = DummyOs::quick_process(size::mb(2), &[]);
+        let addr = proc.info().address;
+        proc.write(addr, &0x1234u64).unwrap();
+
+        // x64 is little endian, so this matches a plain read on little endian hosts
+        let ptr = Pointer64::<u64>::from(addr);
+        let value = ptr.read_arch(&mut proc, x64::ARCH).unwrap();
+        if cfg!(target_endian = "little") {
+            assert_eq!(value, 0x1234);
+        } else {
+            assert_eq!(value, 0x1234u64.swap_bytes());
+        }
+    }
+
+    #[test]
+    fn read_into_slice_zst() {
+        use crate::dummy::DummyOs;
+        use crate::os::Process;
+        use crate::types::size;
+
+        let mut proc = DummyOs::quick_process(size::mb(2), &[]);
+        let addr = proc.info().address;
+
+        let mut buf = [[0u8; 0]; 4];
+        assert_eq!(
+            Pointer64::<[[u8; 0]]>::from(addr).read_into_slice(&mut proc, &mut buf),
+            Err(PartialError::Error(Error(
+                ErrorOrigin::Memory,
+                ErrorKind::InvalidArgument
+            )))
+        );
+    }
 }
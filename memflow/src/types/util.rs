@@ -72,3 +72,170 @@ impl<'a> Extend<MemoryRange> for GapRemover<'a> {
         iter.into_iter().for_each(|r| self.push_range(r));
     }
 }
+
+const HEXDUMP_LINE_LEN: usize = 16;
+
+/// Formats a buffer as a classic hex dump
+///
+/// Every line consists of the address, the hex bytes, and their printable ascii representation.
+///
+/// # Arguments
+///
+/// * `base` - address of the first byte
+/// * `data` - bytes to format
+/// * `addr_size` - size of an address in bytes, as per `Architecture::size_addr`
+pub fn hexdump(base: Address, data: &[u8], addr_size: usize) -> String {
+    let data = data.iter().copied().map(Some).collect::<Vec<_>>();
+    hexdump_partial(base, &data, addr_size)
+}
+
+/// Formats a partially read buffer as a classic hex dump
+///
+/// Bytes that are `None` are printed as `??`.
+///
+/// # Arguments
+///
+/// * `base` - address of the first byte
+/// * `data` - bytes to format
+/// * `addr_size` - size of an address in bytes, as per `Architecture::size_addr`
+///
+/// # Examples
+///
+/// ```
+/// use memflow::types::{util::hexdump_partial, Address};
+///
+/// let dump = hexdump_partial(Address::from(0x1000u64), &[Some(b'h'), Some(b'i'), None], 4);
+/// assert_eq!(
+///     dump,
+///     "00001000  68 69 ??                                          |hi?|\n"
+/// );
+/// ```
+pub fn hexdump_partial(base: Address, data: &[Option<u8>], addr_size: usize) -> String {
+    let mut ret = String::new();
+
+    for (i, line) in data.chunks(HEXDUMP_LINE_LEN).enumerate() {
+        let addr = base + i * HEXDUMP_LINE_LEN;
+        ret.push_str(&format!("{:01$x}  ", addr.to_umem(), addr_size * 2));
+
+        for idx in 0..HEXDUMP_LINE_LEN {
+            if idx == HEXDUMP_LINE_LEN / 2 {
+                ret.push(' ');
+            }
+            match line.get(idx) {
+                Some(Some(b)) => ret.push_str(&format!("{:02x} ", b)),
+                Some(None) => ret.push_str("?? "),
+                None => ret.push_str("   "),
+            }
+        }
+
+        ret.push_str(" |");
+        ret.extend(line.iter().map(|b| match b {
+            Some(b) if b.is_ascii_graphic() || *b == b' ' => *b as char,
+            Some(_) => '.',
+            None => '?',
+        }));
+        ret.push_str("|\n");
+    }
+
+    ret
+}
+
+/// Number of leading code units inspected by [`decode_string_auto`]
+const STRING_AUTO_PROBE_LEN: usize = 4;
+
+/// Decodes a null-terminated string of unknown encoding.
+///
+/// The string is treated as UTF-16 if every code unit within the first few characters
+/// has a non-zero low byte and a zero high byte (i.e. it looks like wide latin text).
+/// Otherwise it is decoded as a narrow string. In both cases decoding stops at the null terminator
+/// or at the end of `data`, invalid characters are replaced with `U+FFFD`.
+///
+/// # Arguments
+///
+/// * `data` - raw bytes of the string
+/// * `little_endian` - byte order of UTF-16 code units
+///
+/// # Examples
+///
+/// ```
+/// use memflow::types::util::decode_string_auto;
+///
+/// assert_eq!(decode_string_auto(b"memflow\0", true), "memflow");
+/// assert_eq!(decode_string_auto(b"m\0e\0m\0\0\0", true), "mem");
+/// ```
+pub fn decode_string_auto(data: &[u8], little_endian: bool) -> String {
+    if string_auto_is_wide(data, little_endian) {
+        let units = data
+            .chunks_exact(2)
+            .map(|c| utf16_unit(c, little_endian))
+            .take_while(|&c| c != 0)
+            .collect::<Vec<_>>();
+        String::from_utf16_lossy(&units)
+    } else {
+        let len = data.iter().position(|&b| b == 0).unwrap_or(data.len());
+        String::from_utf8_lossy(&data[..len]).to_string()
+    }
+}
+
+/// Returns `true` if [`decode_string_auto`] decodes `data` as UTF-16.
+pub(crate) fn string_auto_is_wide(data: &[u8], little_endian: bool) -> bool {
+    let probe = data
+        .chunks_exact(2)
+        .take(STRING_AUTO_PROBE_LEN)
+        .map(|c| utf16_unit(c, little_endian))
+        .take_while(|&c| c != 0)
+        .collect::<Vec<_>>();
+
+    !probe.is_empty() && probe.iter().all(|&c| c & 0xff != 0 && c >> 8 == 0)
+}
+
+#[inline]
+fn utf16_unit(c: &[u8], little_endian: bool) -> u16 {
+    if little_endian {
+        u16::from_le_bytes([c[0], c[1]])
+    } else {
+        u16::from_be_bytes([c[0], c[1]])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hexdump_lines() {
+        let data = (0..20u8).collect::<Vec<_>>();
+        let dump = hexdump(Address::null(), &data, 8);
+        let lines = dump.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0],
+            "0000000000000000  00 01 02 03 04 05 06 07  08 09 0a 0b 0c 0d 0e 0f  |................|"
+        );
+        assert!(lines[1].starts_with("0000000000000010  10 11 12 13  "));
+        assert!(lines[1].ends_with("|....|"));
+    }
+
+    #[test]
+    fn hexdump_empty() {
+        assert_eq!(hexdump(Address::null(), &[], 8), "");
+    }
+
+    #[test]
+    fn hexdump_addr_size() {
+        let dump = hexdump(Address::from(0x1000u64), b"hi", 4);
+        assert!(dump.starts_with("00001000  68 69  "));
+    }
+
+    #[test]
+    fn string_auto_edge_cases() {
+        assert_eq!(decode_string_auto(&[], true), "");
+        assert_eq!(decode_string_auto(&[0, 0, b'a', 0], true), "");
+        // a single narrow character followed by the terminator looks the same in both encodings
+        assert_eq!(decode_string_auto(&[b'a', 0, 0, 0], true), "a");
+        assert_eq!(decode_string_auto(b"ab", true), "ab");
+        assert_eq!(decode_string_auto(&[0, b'h', 0, b'i'], false), "hi");
+        assert_eq!(decode_string_auto(&[b'h', 0, b'i'], true), "h");
+    }
+}